@@ -0,0 +1,86 @@
+//! Benchmarks of BitMatrix multiplication and exponentiation
+//!
+//! Compares the plain `dot()` with the table-driven `dot_table()` (Method of Four Russians), and
+//! `pow()`, which uses `dot_table()`, with exponentiation using `dot()`. Run with:
+//!
+//!     cargo bench --bench bitmatrix
+
+mod common;
+
+use common::time;
+use ssrand::bitmatrix::{BitMatrix, BitMatrixInt};
+use std::hint::black_box;
+
+use num_traits::{One, Pow};
+
+/// A pseudo-random matrix, from xorshift64.
+fn matrix<T: BitMatrixInt, const WIDTH: usize>(seed: u64) -> BitMatrix<T, WIDTH> {
+    let mut x = seed;
+    let mut next = || {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        x
+    };
+    let mask = T::max_value().to_u128().unwrap_or(u128::MAX);
+    let mut columns = [T::zero(); WIDTH];
+    for column in columns.iter_mut() {
+        let bits = ((next() as u128) << 64) | next() as u128;
+        *column = T::from(bits & mask).unwrap_or_else(T::zero);
+    }
+    BitMatrix::new(&columns)
+}
+
+/// Matrix exponentiation using the plain `dot()`, for comparison with `pow()`.
+fn pow_dot<T: BitMatrixInt, const WIDTH: usize>(
+    a: &BitMatrix<T, WIDTH>,
+    n: u64,
+) -> BitMatrix<T, WIDTH> {
+    let mut result = BitMatrix::<T, WIDTH>::one();
+    let mut temp_exp = a.clone();
+    let mut n_work = n;
+    while n_work != 0 {
+        if n_work & 1 != 0 {
+            result = result.dot(&temp_exp);
+        }
+        n_work >>= 1;
+        temp_exp = temp_exp.dot(&temp_exp);
+    }
+    result
+}
+
+fn bench<T: BitMatrixInt, const WIDTH: usize>(name: &str) {
+    let a = matrix::<T, WIDTH>(0x9E3779B97F4A7C15);
+    let b = matrix::<T, WIDTH>(0xD1B54A32D192ED03);
+    let dot = time(|| {
+        black_box(black_box(&a).dot(black_box(&b)));
+    });
+    let dot_table = time(|| {
+        black_box(black_box(&a).dot_table(black_box(&b)));
+    });
+    let pow_dot = time(|| {
+        black_box(pow_dot(black_box(&a), black_box(u64::MAX)));
+    });
+    let pow = time(|| {
+        black_box(black_box(a.clone()).pow(black_box(u64::MAX)));
+    });
+    println!("{}", name);
+    println!(
+        "    dot {:>10.2?}    dot_table {:>10.2?}    speed-up {:>5.2}",
+        dot,
+        dot_table,
+        dot.as_secs_f64() / dot_table.as_secs_f64()
+    );
+    println!(
+        "    pow(2^64-1) via dot {:>10.2?}    pow(2^64-1) {:>10.2?}    speed-up {:>5.2}",
+        pow_dot,
+        pow,
+        pow_dot.as_secs_f64() / pow.as_secs_f64()
+    );
+}
+
+fn main() {
+    bench::<u32, 32>("BitMatrix<u32, 32>");
+    bench::<u64, 64>("BitMatrix<u64, 64>");
+    bench::<u128, 128>("BitMatrix<u128, 128>");
+}
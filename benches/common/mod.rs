@@ -0,0 +1,19 @@
+//! Timing shared by the benchmarks
+
+use std::time::{Duration, Instant};
+
+/// Run `f` repeatedly for about half a second, and return the mean time per call.
+pub fn time<F: FnMut()>(mut f: F) -> Duration {
+    let mut iterations = 1_u32;
+    loop {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            f();
+        }
+        let elapsed = start.elapsed();
+        if elapsed > Duration::from_millis(500) {
+            return elapsed / iterations;
+        }
+        iterations *= 2;
+    }
+}
@@ -0,0 +1,71 @@
+//! Benchmarks of modular multiplication and exponentiation
+//!
+//! Compares `mul_mod()` and `pow_mod()`, which use Montgomery multiplication for odd u64 and u128
+//! moduli, with the generic shift-and-add implementations. Run with:
+//!
+//!     cargo bench --bench math
+
+mod common;
+
+use common::time;
+use ssrand::math;
+use std::hint::black_box;
+
+fn main() {
+    // The MWC64 modulus, and a large odd u128 modulus.
+    let m64 = (698769069_u64 << 32) - 1;
+    let m128 = 0xEC327D45470669CC56B547B6FE6888A3_u128;
+    let a64 = 0x0123456789ABCDEF_u64;
+    let a128 = 0xDC28D76FFD9338E9D868AF566191DE10_u128;
+
+    let generic = time(|| {
+        black_box(math::mul_mod_generic(
+            black_box(a128),
+            black_box(a128),
+            m128,
+        ));
+    });
+    let montgomery = time(|| {
+        black_box(math::mul_mod(black_box(a128), black_box(a128), m128));
+    });
+    println!(
+        "u128 mul_mod: generic {:?}, Montgomery {:?}, speed-up {:.1}×",
+        generic,
+        montgomery,
+        generic.as_secs_f64() / montgomery.as_secs_f64()
+    );
+
+    let generic = time(|| {
+        black_box(math::pow_mod_generic(
+            black_box(a64),
+            black_box(u64::MAX),
+            m64,
+        ));
+    });
+    let montgomery = time(|| {
+        black_box(math::pow_mod(black_box(a64), black_box(u64::MAX), m64));
+    });
+    println!(
+        "u64 pow_mod: widening {:?}, Montgomery {:?}, speed-up {:.1}×",
+        generic,
+        montgomery,
+        generic.as_secs_f64() / montgomery.as_secs_f64()
+    );
+
+    let generic = time(|| {
+        black_box(math::pow_mod_generic(
+            black_box(a128),
+            black_box(u128::MAX),
+            m128,
+        ));
+    });
+    let montgomery = time(|| {
+        black_box(math::pow_mod(black_box(a128), black_box(u128::MAX), m128));
+    });
+    println!(
+        "u128 pow_mod: generic {:?}, Montgomery {:?}, speed-up {:.1}×",
+        generic,
+        montgomery,
+        generic.as_secs_f64() / montgomery.as_secs_f64()
+    );
+}
@@ -0,0 +1,264 @@
+//! Generators selected by name at run time
+//!
+//! [`AnyRng`] wraps any of the generators in this crate, so that the choice of generator can
+//! come from a configuration file or command line, eg `"KISS2"` or `"LFSR113"`. It implements
+//! `RngCore` and [`RngJumpAhead`] by dispatching to the wrapped generator.
+//!
+//! [`GENERATORS`] lists every generator, with its name, number of seeds and period.
+//!
+//!     use rand_core::RngCore;
+//!     use ssrand::anyrng::AnyRng;
+//!
+//!     let mut rng = AnyRng::new("kiss", &[2247183469, 99545079, 3269400377, 3950144837]).unwrap();
+//!     assert_eq!(rng.name(), "KISS");
+//!     let mut kiss = ssrand::KISS::new(2247183469, 99545079, 3269400377, 3950144837);
+//!     assert_eq!(rng.next_u32(), kiss.next_u32());
+//!
+
+use core::fmt;
+
+use rand_core::{Error, RngCore};
+
+use crate::seed::{FromSeedSequence, SeedSequence};
+use crate::{math, Cong, RngJumpAhead, KISS, KISS2, LFSR113, LFSR88, MWC1, MWC2, MWC64, SHR3};
+
+/// Name, number of seeds and period of a generator
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GeneratorInfo {
+    /// Name of the generator type, eg "KISS2"
+    pub name: &'static str,
+    /// Number of 32-bit seeds taken by the generator's `new()`
+    pub seed_count: usize,
+    /// Period of the generator, for non-degenerate seeds (any seeds accepted by its `try_new()`)
+    pub period: u128,
+}
+
+/// Every generator in the crate, in the same order as the variants of [`AnyRng`]
+pub static GENERATORS: [GeneratorInfo; 9] = [
+    GeneratorInfo {
+        name: "Cong",
+        seed_count: 1,
+        period: Cong::PERIOD,
+    },
+    GeneratorInfo {
+        name: "SHR3",
+        seed_count: 1,
+        period: SHR3::PERIOD,
+    },
+    GeneratorInfo {
+        name: "MWC1",
+        seed_count: 2,
+        period: MWC1::PERIOD,
+    },
+    GeneratorInfo {
+        name: "MWC2",
+        seed_count: 2,
+        period: MWC2::PERIOD,
+    },
+    GeneratorInfo {
+        name: "MWC64",
+        seed_count: 2,
+        period: MWC64::PERIOD,
+    },
+    GeneratorInfo {
+        name: "KISS",
+        seed_count: 4,
+        period: KISS::PERIOD,
+    },
+    GeneratorInfo {
+        name: "KISS2",
+        seed_count: 4,
+        period: KISS2::PERIOD,
+    },
+    GeneratorInfo {
+        name: "LFSR88",
+        seed_count: 3,
+        period: LFSR88::PERIOD,
+    },
+    GeneratorInfo {
+        name: "LFSR113",
+        seed_count: 4,
+        period: LFSR113::PERIOD,
+    },
+];
+
+/// Look up a generator by name, ignoring ASCII case.
+pub fn generator_info(name: &str) -> Option<&'static GeneratorInfo> {
+    GENERATORS
+        .iter()
+        .find(|info| info.name.eq_ignore_ascii_case(name))
+}
+
+/// Error from [`AnyRng::new()`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseRngError {
+    /// The name isn't one of the [`GENERATORS`]
+    UnknownName,
+    /// The generator needs a different number of seeds
+    SeedCount { expected: usize, given: usize },
+}
+
+impl fmt::Display for ParseRngError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseRngError::UnknownName => write!(f, "unknown generator name"),
+            ParseRngError::SeedCount { expected, given } => {
+                write!(f, "expected {} seed(s), but {} given", expected, given)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseRngError {}
+
+/// Any of the generators in the crate
+///
+/// The `Debug` form is that of the wrapped generator, eg `KISS2 { .. }`, so that printed states
+/// look the same whether or not the generator was selected by name.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub enum AnyRng {
+    Cong(Cong),
+    SHR3(SHR3),
+    MWC1(MWC1),
+    MWC2(MWC2),
+    MWC64(MWC64),
+    KISS(KISS),
+    KISS2(KISS2),
+    LFSR88(LFSR88),
+    LFSR113(LFSR113),
+}
+
+/// Apply an expression to the generator wrapped by an `AnyRng`, whatever its type.
+macro_rules! dispatch {
+    ($any:expr, $rng:ident => $e:expr) => {
+        match $any {
+            AnyRng::Cong($rng) => $e,
+            AnyRng::SHR3($rng) => $e,
+            AnyRng::MWC1($rng) => $e,
+            AnyRng::MWC2($rng) => $e,
+            AnyRng::MWC64($rng) => $e,
+            AnyRng::KISS($rng) => $e,
+            AnyRng::KISS2($rng) => $e,
+            AnyRng::LFSR88($rng) => $e,
+            AnyRng::LFSR113($rng) => $e,
+        }
+    };
+}
+
+impl AnyRng {
+    /// Create the generator named `name` (ignoring ASCII case), from its seeds
+    ///
+    /// The number of seeds must match the generator's `new()`. See [`GENERATORS`].
+    pub fn new(name: &str, seeds: &[u32]) -> Result<AnyRng, ParseRngError> {
+        let info = generator_info(name).ok_or(ParseRngError::UnknownName)?;
+        if seeds.len() != info.seed_count {
+            return Err(ParseRngError::SeedCount {
+                expected: info.seed_count,
+                given: seeds.len(),
+            });
+        }
+        Ok(match info.name {
+            "Cong" => AnyRng::Cong(Cong::new(seeds[0])),
+            "SHR3" => AnyRng::SHR3(SHR3::new(seeds[0])),
+            "MWC1" => AnyRng::MWC1(MWC1::new(seeds[0], seeds[1])),
+            "MWC2" => AnyRng::MWC2(MWC2::new(seeds[0], seeds[1])),
+            "MWC64" => AnyRng::MWC64(MWC64::new(seeds[0], seeds[1])),
+            "KISS" => AnyRng::KISS(KISS::new(seeds[0], seeds[1], seeds[2], seeds[3])),
+            "KISS2" => AnyRng::KISS2(KISS2::new(seeds[0], seeds[1], seeds[2], seeds[3])),
+            "LFSR88" => AnyRng::LFSR88(LFSR88::new(seeds[0], seeds[1], seeds[2])),
+            "LFSR113" => AnyRng::LFSR113(LFSR113::new(seeds[0], seeds[1], seeds[2], seeds[3])),
+            _ => unreachable!("generator {} is missing from AnyRng::new()", info.name),
+        })
+    }
+
+    /// Create the generator named `name` (ignoring ASCII case), with seeds from `seq`
+    ///
+    /// See [`FromSeedSequence`].
+    pub fn from_seed_sequence(name: &str, seq: &mut SeedSequence) -> Result<AnyRng, ParseRngError> {
+        let info = generator_info(name).ok_or(ParseRngError::UnknownName)?;
+        Ok(match info.name {
+            "Cong" => AnyRng::Cong(Cong::from_seed_sequence(seq)),
+            "SHR3" => AnyRng::SHR3(SHR3::from_seed_sequence(seq)),
+            "MWC1" => AnyRng::MWC1(MWC1::from_seed_sequence(seq)),
+            "MWC2" => AnyRng::MWC2(MWC2::from_seed_sequence(seq)),
+            "MWC64" => AnyRng::MWC64(MWC64::from_seed_sequence(seq)),
+            "KISS" => AnyRng::KISS(KISS::from_seed_sequence(seq)),
+            "KISS2" => AnyRng::KISS2(KISS2::from_seed_sequence(seq)),
+            "LFSR88" => AnyRng::LFSR88(LFSR88::from_seed_sequence(seq)),
+            "LFSR113" => AnyRng::LFSR113(LFSR113::from_seed_sequence(seq)),
+            _ => unreachable!(
+                "generator {} is missing from AnyRng::from_seed_sequence()",
+                info.name
+            ),
+        })
+    }
+
+    /// Name, number of seeds and period of the wrapped generator
+    pub fn info(&self) -> &'static GeneratorInfo {
+        let index = match self {
+            AnyRng::Cong(_) => 0,
+            AnyRng::SHR3(_) => 1,
+            AnyRng::MWC1(_) => 2,
+            AnyRng::MWC2(_) => 3,
+            AnyRng::MWC64(_) => 4,
+            AnyRng::KISS(_) => 5,
+            AnyRng::KISS2(_) => 6,
+            AnyRng::LFSR88(_) => 7,
+            AnyRng::LFSR113(_) => 8,
+        };
+        &GENERATORS[index]
+    }
+
+    /// Name of the wrapped generator, as in [`GENERATORS`]
+    pub fn name(&self) -> &'static str {
+        self.info().name
+    }
+}
+
+impl fmt::Debug for AnyRng {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        dispatch!(self, rng => rng.fmt(f))
+    }
+}
+
+impl RngCore for AnyRng {
+    fn next_u32(&mut self) -> u32 {
+        dispatch!(self, rng => rng.next_u32())
+    }
+    fn next_u64(&mut self) -> u64 {
+        dispatch!(self, rng => rng.next_u64())
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        dispatch!(self, rng => rng.fill_bytes(dest))
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        dispatch!(self, rng => rng.try_fill_bytes(dest))
+    }
+}
+
+impl RngJumpAhead for AnyRng {
+    fn jumpahead<N>(&mut self, n: N)
+    where
+        N: math::JumpCount,
+    {
+        dispatch!(self, rng => rng.jumpahead(n))
+    }
+    fn jumpahead_pow2(&mut self, k: u32) {
+        dispatch!(self, rng => rng.jumpahead_pow2(k))
+    }
+}
+
+macro_rules! from_rng {
+    ($($t:ident),*) => {
+        $(
+            impl From<$t> for AnyRng {
+                fn from(rng: $t) -> AnyRng {
+                    AnyRng::$t(rng)
+                }
+            }
+        )*
+    };
+}
+
+from_rng!(Cong, SHR3, MWC1, MWC2, MWC64, KISS, KISS2, LFSR88, LFSR113);
@@ -26,7 +26,7 @@ impl<T, const WIDTH: usize> BitMatrix<T, WIDTH>
 where
     T: BitMatrixInt,
 {
-    pub fn new(init_data: &[T; WIDTH]) -> BitMatrix<T, WIDTH> {
+    pub const fn new(init_data: &[T; WIDTH]) -> BitMatrix<T, WIDTH> {
         BitMatrix::<T, WIDTH> {
             columns: *init_data,
         }
@@ -77,6 +77,74 @@ where
         result
     }
 
+    /// Number of 4-bit groups of columns, for the lookup tables of `dot_table()`
+    const TABLE_GROUPS: usize = WIDTH.div_ceil(4);
+
+    /// Make the lookup tables for `dot_table()`: the 16 possible sums of each group of 4
+    /// columns.
+    ///
+    /// Array lengths can't be calculated from `WIDTH`, so the number of tables `GROUPS` is a
+    /// parameter, at least `TABLE_GROUPS`. The tables beyond those are left zero.
+    fn make_table<const GROUPS: usize>(&self) -> [[T; 16]; GROUPS] {
+        assert!(
+            Self::TABLE_GROUPS <= GROUPS,
+            "matrix is too wide for dot_table()"
+        );
+        let mut table = [[T::ZERO; 16]; GROUPS];
+        for (g, group_table) in table.iter_mut().enumerate().take(Self::TABLE_GROUPS) {
+            for k in 1..16 {
+                // Each entry is a previous entry plus one column.
+                let column_index = 4 * g + k.trailing_zeros() as usize;
+                let column = if column_index < WIDTH {
+                    self.columns[column_index]
+                } else {
+                    T::ZERO
+                };
+                group_table[k] = group_table[k & (k - 1)] ^ column;
+            }
+        }
+        table
+    }
+
+    /// Multiply the matrix that `table` was made from by `b`.
+    fn dot_with_table<const GROUPS: usize>(
+        table: &[[T; 16]; GROUPS],
+        b: &BitMatrix<T, WIDTH>,
+    ) -> BitMatrix<T, WIDTH> {
+        let nibble_mask = (T::ONE << 4) - T::ONE;
+        let mut result = BitMatrix::<T, WIDTH>::zero();
+        for i in 0..WIDTH {
+            let mut value = T::ZERO;
+            let mut b_temp = b.columns[i];
+            for group_table in table.iter().take(Self::TABLE_GROUPS) {
+                value ^= group_table[(b_temp & nibble_mask).to_usize().unwrap_or(0)];
+                b_temp = b_temp >> 4;
+            }
+            result.columns[i] = value;
+        }
+        result
+    }
+
+    /// Multiply two matrices, using lookup tables (Method of Four Russians).
+    ///
+    /// The columns of `self` are split into groups of 4, and a table of the 16 possible sums of
+    /// each group is made. Then each column of the result needs one table lookup per 4 bits of
+    /// the corresponding column of `b`, rather than one step per bit as in `dot()`. The tables
+    /// are on the stack, with enough for `WIDTH` rounded up to a power of two: 512 bytes for a
+    /// 32×32 matrix of u32, up to 8 KiB for 128×128.
+    ///
+    /// `Pow` uses this. It is much faster than `dot()` for 64×64 matrices and larger, and about
+    /// the same for 32×32.
+    pub fn dot_table(&self, b: &BitMatrix<T, WIDTH>) -> BitMatrix<T, WIDTH> {
+        match Self::TABLE_GROUPS {
+            0..=2 => Self::dot_with_table(&self.make_table::<2>(), b),
+            3..=4 => Self::dot_with_table(&self.make_table::<4>(), b),
+            5..=8 => Self::dot_with_table(&self.make_table::<8>(), b),
+            9..=16 => Self::dot_with_table(&self.make_table::<16>(), b),
+            _ => Self::dot_with_table(&self.make_table::<32>(), b),
+        }
+    }
+
     pub fn dot_equ(&mut self, b: &BitMatrix<T, WIDTH>) {
         let a = BitMatrix::<T, WIDTH> {
             columns: self.columns,
@@ -85,8 +153,303 @@ where
             self.columns[i] = a.dot_vec(b.columns[i]);
         }
     }
+
+    /// Swap two rows of the matrix. Row `i` is bit `i` of each column.
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        let mask_a = T::ONE << a;
+        let mask_b = T::ONE << b;
+        for i in 0..WIDTH {
+            if (self.columns[i] & mask_a == T::ZERO) != (self.columns[i] & mask_b == T::ZERO) {
+                self.columns[i] ^= mask_a | mask_b;
+            }
+        }
+    }
+
+    /// Gauss-Jordan elimination, reducing `self` to reduced row echelon form, and applying the
+    /// same row operations to `augment`.
+    ///
+    /// Returns the rank of `self`.
+    fn reduce_augmented(&mut self, augment: &mut BitMatrix<T, WIDTH>) -> usize {
+        let mut rank = 0;
+        for c in 0..WIDTH {
+            // Find a pivot row, at or below row `rank`, with a 1 in column c.
+            let pivot_bits = self.columns[c] >> rank;
+            if pivot_bits == T::ZERO {
+                continue;
+            }
+            let pivot = rank + pivot_bits.trailing_zeros() as usize;
+            self.swap_rows(rank, pivot);
+            augment.swap_rows(rank, pivot);
+
+            // Clear column c in all other rows, by adding the pivot row to them.
+            let pivot_mask = T::ONE << rank;
+            let clear_mask = self.columns[c] & !pivot_mask;
+            for i in c..WIDTH {
+                if self.columns[i] & pivot_mask != T::ZERO {
+                    self.columns[i] ^= clear_mask;
+                }
+            }
+            for i in 0..WIDTH {
+                if augment.columns[i] & pivot_mask != T::ZERO {
+                    augment.columns[i] ^= clear_mask;
+                }
+            }
+
+            rank += 1;
+            if rank == WIDTH {
+                break;
+            }
+        }
+        rank
+    }
+
+    /// Row-reduce the matrix by Gaussian elimination.
+    ///
+    /// Returns the reduced row echelon form of the matrix, and the rank of the matrix.
+    pub fn row_reduce(&self) -> (BitMatrix<T, WIDTH>, usize) {
+        let mut result = self.clone();
+        let rank = result.reduce_augmented(&mut BitMatrix::<T, WIDTH>::zero());
+        (result, rank)
+    }
+
+    /// Rank of the matrix.
+    pub fn rank(&self) -> usize {
+        self.row_reduce().1
+    }
+
+    /// Determinant of the matrix, which in Galois(2) is either 0 or 1.
+    ///
+    /// It is 1 if and only if the matrix is invertible.
+    pub fn determinant(&self) -> u8 {
+        if self.rank() == WIDTH {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Transpose of the matrix, ie rows and columns swapped.
+    pub fn transpose(&self) -> BitMatrix<T, WIDTH> {
+        let mut result = BitMatrix::<T, WIDTH>::zero();
+        for i in 0..WIDTH {
+            let mut column = self.columns[i];
+            while column != T::ZERO {
+                let j = column.trailing_zeros() as usize;
+                result.columns[j] ^= T::ONE << i;
+                column = column & (column - T::ONE);
+            }
+        }
+        result
+    }
+
+    /// Inverse of the matrix, or `None` if the matrix is singular.
+    ///
+    /// For the matrix of a generator's step, the inverse steps the generator backwards.
+    pub fn inverse(&self) -> Option<BitMatrix<T, WIDTH>> {
+        let mut reduced = self.clone();
+        let mut result = BitMatrix::<T, WIDTH>::one();
+        if reduced.reduce_augmented(&mut result) == WIDTH {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Raise a matrix to a power, which may be negative.
+    ///
+    /// Returns `None` if the exponent is negative and the matrix is singular.
+    pub fn checked_pow<N>(&self, n: N) -> Option<BitMatrix<T, WIDTH>>
+    where
+        N: crate::math::IntTypes,
+    {
+        let base = if n < N::ZERO {
+            self.inverse()?
+        } else {
+            self.clone()
+        };
+        Some(base.pow_unsigned(N::abs_as_unsigned(n)))
+    }
+
+    /// Raise a matrix to a non-negative power. Efficient matrix exponentiation, using
+    /// `dot_table()` for the multiplications.
+    fn pow_unsigned<N>(self, n: N) -> BitMatrix<T, WIDTH>
+    where
+        N: Unsigned + PrimInt + ConstOne + ConstZero,
+    {
+        // Tables sized as for dot_table().
+        match Self::TABLE_GROUPS {
+            0..=2 => self.pow_unsigned_groups::<N, 2>(n),
+            3..=4 => self.pow_unsigned_groups::<N, 4>(n),
+            5..=8 => self.pow_unsigned_groups::<N, 8>(n),
+            9..=16 => self.pow_unsigned_groups::<N, 16>(n),
+            _ => self.pow_unsigned_groups::<N, 32>(n),
+        }
+    }
+
+    /// `pow_unsigned()`, with lookup tables of `GROUPS` groups
+    fn pow_unsigned_groups<N, const GROUPS: usize>(self, n: N) -> BitMatrix<T, WIDTH>
+    where
+        N: Unsigned + PrimInt + ConstOne + ConstZero,
+    {
+        let mut result = BitMatrix::<T, WIDTH>::one();
+        let mut temp_exp = self;
+        let mut n_work: N = n;
+
+        loop {
+            // All the matrices are powers of `self`, so they commute, and one table serves for
+            // both multiplications.
+            let table = temp_exp.make_table::<GROUPS>();
+            if n_work & N::ONE != N::ZERO {
+                result = Self::dot_with_table(&table, &result);
+            }
+            n_work = n_work >> 1;
+            if n_work == N::ZERO {
+                break;
+            }
+            temp_exp = Self::dot_with_table(&table, &temp_exp);
+        }
+        result
+    }
+
+    /// Minimal polynomial of the vector `v`, ie the polynomial p of lowest degree for which
+    /// p(M) v = 0, by finding the first linear dependency in v, Mv, M²v, ...
+    fn vector_minimal_polynomial(&self, v: T) -> u128 {
+        // Reduced vectors found so far, indexed by their highest set bit, each with the
+        // polynomial in M that gives it.
+        let mut basis = [T::ZERO; WIDTH];
+        let mut basis_poly = [0_u128; WIDTH];
+        let mut krylov = v;
+        for k in 0..=WIDTH {
+            let mut w = krylov;
+            let mut poly = 1_u128 << k;
+            while w != T::ZERO {
+                let top = crate::math::size_of_bits::<T>() - 1 - w.leading_zeros() as usize;
+                if basis_poly[top] == 0 {
+                    basis[top] = w;
+                    basis_poly[top] = poly;
+                    break;
+                }
+                w ^= basis[top];
+                poly ^= basis_poly[top];
+            }
+            if w == T::ZERO {
+                return poly;
+            }
+            krylov = self.dot_vec(krylov);
+        }
+        unreachable!("more than WIDTH linearly independent vectors")
+    }
+
+    /// Minimal polynomial of the matrix.
+    ///
+    /// That is, the polynomial p of lowest degree for which p(M) = 0, in the representation used
+    /// by [`gf2poly`](crate::gf2poly). It is the least common multiple of the minimal polynomials
+    /// of the unit vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `WIDTH` is more than 127.
+    pub fn minimal_polynomial(&self) -> u128 {
+        assert!(WIDTH < 128, "matrix is too wide for a u128 polynomial");
+        let mut result = 1;
+        for i in 0..WIDTH {
+            let unit = T::ONE << i;
+            result = crate::gf2poly::lcm(result, self.vector_minimal_polynomial(unit));
+        }
+        result
+    }
+
+    /// Check that `period` is the period of the matrix.
+    ///
+    /// That is, `period` is the smallest p > 0 for which M^(p+WIDTH) = M^WIDTH. For a generator's
+    /// step matrix, that means every state repeats after `period` steps, and some states need
+    /// that many. M^WIDTH is used rather than the identity matrix, so singular matrices can be
+    /// checked: after `WIDTH` steps, every state is on a cycle.
+    pub fn verify_period(&self, period: u128) -> bool {
+        if period == 0 {
+            return false;
+        }
+        let base = self.clone().pow(WIDTH as u128);
+        if self.clone().pow(period + WIDTH as u128) != base {
+            return false;
+        }
+        crate::math::prime_factors(period)
+            .all(|q| self.clone().pow(period / q + WIDTH as u128) != base)
+    }
+}
+
+// `const fn` versions of `dot_vec()`, `dot()` and `pow()`, for concrete column types, since the
+// generic versions use num-traits trait methods which can't be called at compile time.
+macro_rules! const_bitmatrix_fns {
+    ($t:ty) => {
+        impl<const WIDTH: usize> BitMatrix<$t, WIDTH> {
+            /// `const fn` version of `dot_vec()`.
+            pub const fn const_dot_vec(&self, b: $t) -> $t {
+                let mut result: $t = 0;
+                let mut b_temp = b;
+                let mut i = 0;
+                while i < WIDTH {
+                    if b_temp & 1 != 0 {
+                        result ^= self.columns[i];
+                    }
+                    b_temp >>= 1;
+                    i += 1;
+                }
+                result
+            }
+
+            /// `const fn` version of `dot()`.
+            pub const fn const_dot(&self, b: &BitMatrix<$t, WIDTH>) -> BitMatrix<$t, WIDTH> {
+                let mut columns: [$t; WIDTH] = [0; WIDTH];
+                let mut i = 0;
+                while i < WIDTH {
+                    columns[i] = self.const_dot_vec(b.columns[i]);
+                    i += 1;
+                }
+                BitMatrix::<$t, WIDTH> { columns }
+            }
+
+            /// `const fn` version of `pow()`, for a non-negative power.
+            ///
+            ///     use ssrand::bitmatrix::BitMatrix;
+            ///     const SHIFT_3: BitMatrix<u32, 32> = BitMatrix::<u32, 32>::new(&[
+            ///         0x2, 0x4, 0x8, 0x10, 0x20, 0x40, 0x80, 0x100, 0x200, 0x400, 0x800, 0x1000,
+            ///         0x2000, 0x4000, 0x8000, 0x10000, 0x20000, 0x40000, 0x80000, 0x100000,
+            ///         0x200000, 0x400000, 0x800000, 0x1000000, 0x2000000, 0x4000000, 0x8000000,
+            ///         0x10000000, 0x20000000, 0x40000000, 0x80000000, 0x0,
+            ///     ])
+            ///     .const_pow(3);
+            ///     assert_eq!(SHIFT_3.const_dot_vec(1), 8);
+            ///
+            pub const fn const_pow(&self, n: u128) -> BitMatrix<$t, WIDTH> {
+                let mut columns: [$t; WIDTH] = [0; WIDTH];
+                let mut i = 0;
+                while i < WIDTH {
+                    columns[i] = 1 << i;
+                    i += 1;
+                }
+                let mut result = BitMatrix::<$t, WIDTH> { columns };
+                let mut temp_exp = BitMatrix::<$t, WIDTH> {
+                    columns: self.columns,
+                };
+                let mut n_work = n;
+                while n_work != 0 {
+                    if n_work & 1 != 0 {
+                        result = result.const_dot(&temp_exp);
+                    }
+                    n_work >>= 1;
+                    temp_exp = temp_exp.const_dot(&temp_exp);
+                }
+                result
+            }
+        }
+    };
 }
 
+const_bitmatrix_fns!(u32);
+const_bitmatrix_fns!(u64);
+const_bitmatrix_fns!(u128);
+
 impl<T, const WIDTH: usize> Zero for BitMatrix<T, WIDTH>
 where
     T: BitMatrixInt,
@@ -129,30 +492,21 @@ where
 impl<N, T, const WIDTH: usize> Pow<N> for BitMatrix<T, WIDTH>
 where
     T: BitMatrixInt,
-    N: Unsigned + PrimInt + BitAnd + ConstOne + ConstZero,
+    N: crate::math::IntTypes,
 {
     type Output = Self;
 
     /// Raise a matrix to a power. Efficient matrix exponentiation.
+    ///
+    /// A negative power raises the inverse of the matrix. See `checked_pow()` for a version that
+    /// doesn't panic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the power is negative and the matrix is singular.
     fn pow(self, n: N) -> BitMatrix<T, WIDTH> {
-        let mut result = BitMatrix::<T, WIDTH>::one();
-        let mut temp_exp = BitMatrix::<T, WIDTH> {
-            columns: self.columns,
-        };
-        let mut n_work: N = n;
-
-        loop {
-            if n_work & N::ONE != N::ZERO {
-                result.dot_equ(&temp_exp);
-            }
-            n_work = n_work >> 1;
-            if n_work == N::ZERO {
-                break;
-            }
-            let temp_exp2 = temp_exp.clone();
-            temp_exp.dot_equ(&temp_exp2);
-        }
-        result
+        self.checked_pow(n)
+            .expect("negative power of a singular matrix")
     }
 }
 
@@ -217,6 +571,8 @@ where
     type Output = Self;
 
     /// Add two matrices.
+    /// Addition in Galois(2) is exclusive-or.
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn add(self, b: BitMatrix<T, WIDTH>) -> BitMatrix<T, WIDTH> {
         let mut result = BitMatrix::<T, WIDTH> {
             columns: self.columns,
@@ -228,14 +584,14 @@ where
     }
 }
 
-impl<'a, 'b, T, const WIDTH: usize> core::ops::Add<&'b BitMatrix<T, WIDTH>>
-    for &'a BitMatrix<T, WIDTH>
+impl<'b, T, const WIDTH: usize> core::ops::Add<&'b BitMatrix<T, WIDTH>> for &BitMatrix<T, WIDTH>
 where
     T: BitMatrixInt,
 {
     type Output = BitMatrix<T, WIDTH>;
 
     /// Add two matrices (by reference).
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn add(self, b: &'b BitMatrix<T, WIDTH>) -> BitMatrix<T, WIDTH> {
         let mut result = BitMatrix::<T, WIDTH> {
             columns: self.columns,
@@ -247,6 +603,19 @@ where
     }
 }
 
+impl<T, const WIDTH: usize> core::ops::Neg for BitMatrix<T, WIDTH>
+where
+    T: BitMatrixInt,
+{
+    type Output = Self;
+
+    /// Negate a matrix, ie its additive inverse.
+    /// In Galois(2), every matrix is its own additive inverse.
+    fn neg(self) -> BitMatrix<T, WIDTH> {
+        self
+    }
+}
+
 impl<T, const WIDTH: usize> core::ops::Mul for BitMatrix<T, WIDTH>
 where
     T: BitMatrixInt,
@@ -259,8 +628,7 @@ where
     }
 }
 
-impl<'a, 'b, T, const WIDTH: usize> core::ops::Mul<&'b BitMatrix<T, WIDTH>>
-    for &'a BitMatrix<T, WIDTH>
+impl<'b, T, const WIDTH: usize> core::ops::Mul<&'b BitMatrix<T, WIDTH>> for &BitMatrix<T, WIDTH>
 where
     T: BitMatrixInt,
 {
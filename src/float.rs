@@ -0,0 +1,84 @@
+//! Floating-point output
+//!
+//! The conversions of `rand`'s `Standard` distribution differ from those of the C and Python
+//! ports, so [`RngFloat`] provides its own, each fully specified below so that every port gives
+//! identical values. Each uses correctly rounded IEEE 754 operations on the 32-bit outputs of
+//! `next_u32()`, in order, so the results don't depend on the platform.
+//!
+//! | Method              | Interval | Conversion of outputs `x`, `y`
+//! | ------------------- | -------- | ------------------------------------------
+//! | `next_f32()`        | [0, 1)   | `(x >> 8) * 2^-24`
+//! | `next_f32_open()`   | (0, 1)   | `((x >> 9) + 0.5) * 2^-23`
+//! | `next_f32_closed()` | [0, 1]   | `(x >> 8) / (2^24 - 1)`
+//! | `next_f64()`        | [0, 1)   | `((x >> 5) * 2^26 + (y >> 6)) * 2^-53`
+//! | `next_f64_32()`     | [0, 1)   | `x * 2^-32`
+//! | `next_f64_open()`   | (0, 1)   | `(x + 0.5) * 2^-32`
+//! | `next_f64_closed()` | [0, 1]   | `x / (2^32 - 1)`
+//!
+//! `next_f64()` uses all 53 bits of the mantissa, from two outputs, as in `genrand_res53()` of
+//! the Mersenne Twister reference code and Python's `random.random()`. `next_f64_32()` is the
+//! conversion in L'Ecuyer's [`lfsr113.c`](http://www.iro.umontreal.ca/~simardr/rng/lfsr113.c),
+//! so with the same state [`LFSR113`] gives exactly its values.
+//!
+//!     use ssrand::float::RngFloat;
+//!
+//!     let mut rng = ssrand::KISS::new(2247183469, 99545079, 3269400377, 3950144837);
+//!     let x = rng.next_f64();
+//!     assert!((0.0..1.0).contains(&x));
+//!
+//! [`LFSR113`]: crate::LFSR113
+
+use rand_core::RngCore;
+
+const TWO_POW_MINUS_23: f32 = 1.0 / 8388608.0;
+const TWO_POW_MINUS_24: f32 = 1.0 / 16777216.0;
+const TWO_POW_26: f64 = 67108864.0;
+const TWO_POW_MINUS_32: f64 = 1.0 / 4294967296.0;
+const TWO_POW_MINUS_53: f64 = 1.0 / 9007199254740992.0;
+
+/// Portable floating-point output, for any `RngCore`
+///
+/// See the [module documentation](self) for the exact conversions.
+pub trait RngFloat: RngCore {
+    /// An `f32` in [0, 1), from the upper 24 bits of one output
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 * TWO_POW_MINUS_24
+    }
+
+    /// An `f32` in (0, 1), from the upper 23 bits of one output, offset by half a step
+    fn next_f32_open(&mut self) -> f32 {
+        ((self.next_u32() >> 9) as f32 + 0.5) * TWO_POW_MINUS_23
+    }
+
+    /// An `f32` in [0, 1], from the upper 24 bits of one output
+    fn next_f32_closed(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / 16777215.0
+    }
+
+    /// An `f64` in [0, 1), with 53 random bits from two outputs
+    ///
+    /// The upper 27 bits of the first output are the high bits, and the upper 26 bits of the
+    /// second output are the low bits.
+    fn next_f64(&mut self) -> f64 {
+        let high = self.next_u32() >> 5;
+        let low = self.next_u32() >> 6;
+        (high as f64 * TWO_POW_26 + low as f64) * TWO_POW_MINUS_53
+    }
+
+    /// An `f64` in [0, 1), from one output times 2^-32, as in L'Ecuyer's `lfsr113.c`
+    fn next_f64_32(&mut self) -> f64 {
+        self.next_u32() as f64 * TWO_POW_MINUS_32
+    }
+
+    /// An `f64` in (0, 1), from one output, offset by half a step
+    fn next_f64_open(&mut self) -> f64 {
+        (self.next_u32() as f64 + 0.5) * TWO_POW_MINUS_32
+    }
+
+    /// An `f64` in [0, 1], from one output
+    fn next_f64_closed(&mut self) -> f64 {
+        self.next_u32() as f64 / 4294967295.0
+    }
+}
+
+impl<R> RngFloat for R where R: RngCore + ?Sized {}
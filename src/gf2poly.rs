@@ -0,0 +1,229 @@
+//! Polynomials over Galois(2)
+//!
+//! A polynomial is represented by a `u128`, where bit `i` is the coefficient of x^i. Eg
+//! x^3 + x + 1 is `0b1011`. So polynomials up to degree 127 are supported.
+//!
+//! These are used to analyse the linear (GF(2)) generators, eg to check that a generator's
+//! characteristic polynomial is primitive, which proves that it has the maximum period.
+
+use crate::math;
+
+/// Degree of a polynomial, or `None` for the zero polynomial.
+///
+///     use ssrand::gf2poly::degree;
+///     assert_eq!(degree(0b1011), Some(3));
+///     assert_eq!(degree(1), Some(0));
+///     assert_eq!(degree(0), None);
+///
+pub fn degree(a: u128) -> Option<u32> {
+    if a == 0 {
+        None
+    } else {
+        Some(127 - a.leading_zeros())
+    }
+}
+
+/// Multiply two polynomials.
+///
+/// The degree of the product must be at most 127.
+pub fn mul(a: u128, b: u128) -> u128 {
+    let mut result = 0;
+    let mut b_work = b;
+    while b_work != 0 {
+        let i = b_work.trailing_zeros();
+        result ^= a << i;
+        b_work &= b_work - 1;
+    }
+    result
+}
+
+/// Divide polynomial `a` by `b`, giving the quotient and remainder.
+///
+/// # Panics
+///
+/// Panics if `b` is zero.
+pub fn div_rem(a: u128, b: u128) -> (u128, u128) {
+    let b_degree = degree(b).expect("division by the zero polynomial");
+    let mut quotient = 0;
+    let mut remainder = a;
+    while let Some(r_degree) = degree(remainder) {
+        if r_degree < b_degree {
+            break;
+        }
+        let shift = r_degree - b_degree;
+        quotient ^= 1 << shift;
+        remainder ^= b << shift;
+    }
+    (quotient, remainder)
+}
+
+/// Multiply polynomials `a` and `b`, modulo `m`.
+///
+/// `a` and `b` must already be reduced modulo `m`.
+pub fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    let m_top = 1 << degree(m).expect("modulo the zero polynomial");
+    let mut result = 0;
+    let mut a_work = a;
+    let mut b_work = b;
+    while b_work != 0 {
+        if b_work & 1 != 0 {
+            result ^= a_work;
+        }
+        b_work >>= 1;
+        a_work <<= 1;
+        if a_work & m_top != 0 {
+            a_work ^= m;
+        }
+    }
+    result
+}
+
+/// Raise polynomial `a` to the power `n`, modulo `m`.
+///
+///     use ssrand::gf2poly::pow_mod;
+///     // x^7 = 1 modulo x^3 + x + 1
+///     assert_eq!(pow_mod(0b10, 7, 0b1011), 1);
+///
+pub fn pow_mod(a: u128, n: u128, m: u128) -> u128 {
+    let mut result = div_rem(1, m).1;
+    let mut temp_exp = div_rem(a, m).1;
+    let mut n_work = n;
+    while n_work != 0 {
+        if n_work & 1 != 0 {
+            result = mul_mod(result, temp_exp, m);
+        }
+        n_work >>= 1;
+        temp_exp = mul_mod(temp_exp, temp_exp, m);
+    }
+    result
+}
+
+/// Greatest common divisor of two polynomials.
+pub fn gcd(a: u128, b: u128) -> u128 {
+    let mut a_work = a;
+    let mut b_work = b;
+    while b_work != 0 {
+        let r = div_rem(a_work, b_work).1;
+        a_work = b_work;
+        b_work = r;
+    }
+    a_work
+}
+
+/// Least common multiple of two polynomials.
+///
+/// The degree of the result must be at most 127.
+pub fn lcm(a: u128, b: u128) -> u128 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        mul(div_rem(a, gcd(a, b)).0, b)
+    }
+}
+
+/// x^(2^k) modulo `m`.
+fn x_pow2_mod(k: u32, m: u128) -> u128 {
+    let mut result = div_rem(0b10, m).1;
+    for _ in 0..k {
+        result = mul_mod(result, result, m);
+    }
+    result
+}
+
+/// Test whether a polynomial is irreducible, by Rabin's test.
+///
+/// A polynomial `p` of degree n is irreducible if and only if x^(2^n) = x modulo `p`, and
+/// x^(2^(n/q)) - x is coprime to `p` for every prime q dividing n.
+///
+///     use ssrand::gf2poly::is_irreducible;
+///     assert!(is_irreducible(0b1011)); // x^3 + x + 1
+///     assert!(!is_irreducible(0b101)); // x^2 + 1 = (x + 1)^2
+///
+pub fn is_irreducible(p: u128) -> bool {
+    let n = match degree(p) {
+        None | Some(0) => return false,
+        Some(1) => return true,
+        Some(n) => n,
+    };
+    if x_pow2_mod(n, p) != 0b10 {
+        return false;
+    }
+    math::prime_factors(n).all(|q| gcd(x_pow2_mod(n / q, p) ^ 0b10, p) == 1)
+}
+
+/// Test whether a polynomial is primitive.
+///
+/// A polynomial `p` of degree n is primitive if it is irreducible, and x has multiplicative order
+/// 2^n-1 modulo `p`. The linear recurrence with a primitive characteristic polynomial has maximal
+/// period 2^n-1.
+///
+/// The prime factors of 2^n-1 are found by trial division, so this is slow for some degrees above
+/// about 100.
+///
+///     use ssrand::gf2poly::is_primitive;
+///     assert!(is_primitive(0b1011)); // x^3 + x + 1
+///     assert!(!is_primitive(0b11111)); // x^4 + x^3 + x^2 + x + 1 is irreducible, order 5
+///
+pub fn is_primitive(p: u128) -> bool {
+    if p & 1 == 0 || !is_irreducible(p) {
+        return false;
+    }
+    let n = degree(p).unwrap_or(0);
+    let order = u128::MAX >> (128 - n);
+    math::prime_factors(order).all(|q| pow_mod(0b10, order / q, p) != 1)
+}
+
+/// Minimal polynomial of a bit sequence, by the Berlekamp–Massey algorithm.
+///
+/// Returns the polynomial of lowest degree L such that the sequence satisfies the linear
+/// recurrence it defines. L is the linear complexity of the sequence. 2L bits of the sequence are
+/// enough to determine it.
+///
+/// # Panics
+///
+/// Panics if the linear complexity is more than 127.
+///
+///     use ssrand::gf2poly::berlekamp_massey;
+///     // s(n) = s(n-1) + s(n-3)
+///     let bits = [true, false, false, true, true, true, false, true, false, false];
+///     assert_eq!(berlekamp_massey(bits.iter().copied()), 0b1101);
+///
+pub fn berlekamp_massey<I>(bits: I) -> u128
+where
+    I: IntoIterator<Item = bool>,
+{
+    // Connection polynomial, ie the reciprocal of the minimal polynomial.
+    let mut c: u128 = 1;
+    // Connection polynomial before the last length change.
+    let mut b: u128 = 1;
+    let mut length: u32 = 0;
+    let mut m: u32 = 1;
+    // The most recent bits, with the previous bit in bit 0.
+    let mut history: u128 = 0;
+
+    for (n, bit) in bits.into_iter().enumerate() {
+        let discrepancy = bit ^ (((c >> 1) & history).count_ones() & 1 != 0);
+        if discrepancy {
+            let new_length = if 2 * length as usize <= n {
+                n as u32 + 1 - length
+            } else {
+                length
+            };
+            assert!(new_length < 128, "linear complexity is more than 127");
+            let c_prev = c;
+            c ^= b << m;
+            if new_length != length {
+                length = new_length;
+                b = c_prev;
+                m = 1;
+            } else {
+                m += 1;
+            }
+        } else {
+            m += 1;
+        }
+        history = (history << 1) | bit as u128;
+    }
+    // Reverse the connection polynomial, relative to degree `length`.
+    c.reverse_bits() >> (127 - length)
+}
@@ -7,6 +7,10 @@
 //!     * Generate "next" random value
 //!     * "Jump-ahead" (also known as "discard" in C++) to skip the generator
 //!       ahead by 'n' samples.
+//!     * Floating-point output, with conversions that are the same in every language. See
+//!       [`float`].
+//!     * Unbiased integers in a range, with an algorithm that is the same in every language. See
+//!       [`bounded`].
 //! * Simple algorithms that are easily ported to different languages.
 //! * Safe seeding. Many generators have some "bad" state values that must be avoided. The seed
 //!   functions for all generators ensure that any "bad" state values are avoided, and replaced by
@@ -26,6 +30,19 @@
 //! * Reasonable statistical properties of pseudo-random output (though not for all generators
 //!   provided).
 //!
+//! ## Optional Features
+//!
+//! The crate is `no_std` by default. These cargo features add functionality:
+//!
+//! * `std`: Enables the [`stats`] module of statistical tests, which needs the standard library.
+//!   It implies `alloc`.
+//! * `alloc`: Enables the [`widebitmatrix`] module, whose matrices are too big for the stack.
+//!   The crate is still `no_std`.
+//! * `getrandom`: Adds `from_os_rng()` and `try_from_os_rng()` to every generator, seeded by the
+//!   operating system through `new()`, eg `KISS::from_os_rng()`. It also enables `rand_core`'s
+//!   `SeedableRng::from_entropy()`, which goes through `from_seed()` instead. The crate is still
+//!   `no_std`.
+//!
 //! ## Algorithms
 //!
 //! Most algorithms were obtained from two newsgroup posts by George Marsaglia [[mars1]](#mars1)
@@ -92,21 +109,236 @@
 
 #![no_std]
 
-use core::ops::SubAssign;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::hash::{Hash, Hasher};
 use num_traits::{Pow, PrimInt, Unsigned, WrappingAdd, WrappingMul};
-use rand_core::{impls, Error, RngCore};
+use rand_core::{impls, Error, RngCore, SeedableRng};
 
+use seed::{FromSeedSequence, SeedError, SeedSequence};
+
+pub mod anyrng;
 pub mod bitmatrix;
+pub mod bounded;
+pub mod float;
+pub mod gf2poly;
 pub mod math;
+pub mod mwcsearch;
+pub mod seed;
+pub mod spectral;
+#[cfg(feature = "std")]
+pub mod stats;
+#[cfg(feature = "alloc")]
+pub mod widebitmatrix;
 
+/// Random number generators which can jump ahead (or back) in their sequence
 pub trait RngJumpAhead {
+    /// Advance the generator by `n` steps, as if `next_u32()` had been called `n` times
+    ///
+    /// `n` can be any primitive integer type: `u8` to `u128`, `i8` to `i128`, `usize` or `isize`,
+    /// or a [`math::BigUint`] for larger counts. A negative `n` jumps backwards. `n` is first
+    /// reduced modulo the generator's cycle length, a non-zero constant, using
+    /// [`math::modulo()`], so this never panics, for any value of `n` of any of those types.
     fn jumpahead<N>(&mut self, n: N)
     where
-        N: math::IntTypes;
+        N: math::JumpCount;
+
+    /// Advance the generator by 2^`k` steps
+    ///
+    /// This gives the same result as `jumpahead()` by 2^k, for any `k`, which suits
+    /// partitioning a sequence into substreams. By default, it's `jumpahead()` by
+    /// [`math::Pow2`], which reduces 2^k modulo the cycle length, but the generators here
+    /// override it with something faster. The MWC multipliers are squared `k` times, `Cong` uses
+    /// a closed form, and the GF(2) generators (SHR3 and the LFSRs) look up precomputed M^(2^k)
+    /// matrices.
+    fn jumpahead_pow2(&mut self, k: u32) {
+        self.jumpahead(math::Pow2(k));
+    }
+}
+
+/// Object-safe companion to [`RngJumpAhead`], for dynamic dispatch
+///
+/// `RngJumpAhead::jumpahead()` is generic over the count type, so `RngJumpAhead` can't be used as
+/// a trait object. This trait has a method for each of a few fixed count types instead, and is
+/// implemented for every `RngJumpAhead` type. See also [`DynRng`].
+pub trait RngJumpAheadDyn {
+    /// Advance the generator by `n` steps
+    fn jumpahead_u128(&mut self, n: u128);
+
+    /// Advance the generator by `n` steps, or back if `n` is negative
+    fn jumpahead_i128(&mut self, n: i128);
+
+    /// Advance the generator by `n` steps, for a 256-bit `n`
+    fn jumpahead_u256(&mut self, n: math::BigUint<4>);
+
+    /// Advance the generator by 2^`k` steps, as [`RngJumpAhead::jumpahead_pow2()`] does
+    ///
+    /// It has a different name, so that calls to `jumpahead_pow2()` aren't ambiguous when both
+    /// traits are in scope.
+    fn jumpahead_pow2_dyn(&mut self, k: u32);
 }
 
+impl<T> RngJumpAheadDyn for T
+where
+    T: RngJumpAhead,
+{
+    fn jumpahead_u128(&mut self, n: u128) {
+        self.jumpahead(n);
+    }
+    fn jumpahead_i128(&mut self, n: i128) {
+        self.jumpahead(n);
+    }
+    fn jumpahead_u256(&mut self, n: math::BigUint<4>) {
+        self.jumpahead(n);
+    }
+    fn jumpahead_pow2_dyn(&mut self, k: u32) {
+        self.jumpahead_pow2(k);
+    }
+}
+
+/// A random number generator that can jump ahead, usable as a trait object
+///
+/// Eg a table of `Box<dyn DynRng>` can hold any of the generators. It is implemented for every
+/// type that implements both `RngCore` and [`RngJumpAheadDyn`].
+///
+///     use ssrand::{DynRng, RngJumpAheadDyn};
+///     let mut generators: [&mut dyn DynRng; 2] =
+///         [&mut ssrand::KISS::new(1, 2, 3, 4), &mut ssrand::LFSR113::new(1, 2, 3, 4)];
+///     for rng in generators.iter_mut() {
+///         rng.jumpahead_i128(-1_000_000);
+///         let _ = rng.next_u32();
+///     }
+///
+pub trait DynRng: RngCore + RngJumpAheadDyn {}
+
+impl<T> DynRng for T where T: RngCore + RngJumpAheadDyn + ?Sized {}
+
 type BitMatrix32 = bitmatrix::BitMatrix<u32, 32>;
 
+/// Least common multiple, for combining the periods of components at compile time.
+const fn lcm_u128(a: u128, b: u128) -> u128 {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        let r = x % y;
+        x = y;
+        y = r;
+    }
+    a / x * b
+}
+
+/// `Ok(rng)`, or the first degenerate seed found for `try_new()`.
+fn seed_result<T>(rng: T, error: Option<SeedError>) -> Result<T, SeedError> {
+    match error {
+        Some(error) => Err(error),
+        None => Ok(rng),
+    }
+}
+
+/// Implement `FromSeedSequence`, and `SeedableRng` using it, for a generator that takes `$n` seeds,
+/// and with the `getrandom` feature, constructors seeded by the operating system.
+///
+/// The `SeedableRng` seed is `4 * $n` bytes, which are mixed by `SeedSequence::from_bytes()`,
+/// rather than used directly, so that each byte affects every seed word and degenerate seeds are
+/// skipped. The mixing is a bijection, so different seeds give different generators, unless a
+/// set of seed words is skipped. `seed_from_u64()` uses `SeedSequence::from_u64()`.
+macro_rules! seedable_rng {
+    ($t:ident, $n:expr, |$seeds:ident| $($seed:expr),+) => {
+        impl FromSeedSequence for $t {
+            fn from_seed_sequence(seq: &mut SeedSequence) -> $t {
+                seq.generate(|$seeds: [u32; $n]| $t::try_new($($seed),+))
+            }
+        }
+        impl SeedableRng for $t {
+            type Seed = [u8; 4 * $n];
+
+            fn from_seed(seed: Self::Seed) -> $t {
+                $t::from_seed_sequence(&mut SeedSequence::from_bytes(&seed))
+            }
+            fn seed_from_u64(state: u64) -> $t {
+                $t::from_seed_sequence(&mut SeedSequence::from_u64(state))
+            }
+        }
+        #[cfg(feature = "getrandom")]
+        impl $t {
+            /// Create a generator with seeds from the operating system, so it isn't reproducible
+            ///
+            /// The seeds go through `new()`, and are sanitised as usual when the generator is
+            /// used. Requires the `getrandom` feature.
+            ///
+            /// # Panics
+            ///
+            /// If the operating system can't provide random data. See `try_from_os_rng()`.
+            pub fn from_os_rng() -> $t {
+                $t::try_from_os_rng().expect("failed to get seeds from the operating system")
+            }
+
+            /// Create a generator with seeds from the operating system, or the error if it can't
+            /// provide them. See `from_os_rng()`.
+            pub fn try_from_os_rng() -> Result<$t, Error> {
+                let mut bytes = [0u8; 4 * $n];
+                rand_core::OsRng.try_fill_bytes(&mut bytes)?;
+                let mut $seeds = [0u32; $n];
+                for (seed, chunk) in $seeds.iter_mut().zip(bytes.chunks_exact(4)) {
+                    *seed = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                }
+                Ok($t::new($($seed),+))
+            }
+        }
+    };
+}
+
+seedable_rng!(Cong, 1, |s| s[0]);
+seedable_rng!(SHR3, 1, |s| s[0]);
+seedable_rng!(MWC1, 2, |s| s[0], s[1]);
+seedable_rng!(MWC2, 2, |s| s[0], s[1]);
+seedable_rng!(MWC64, 2, |s| s[0], s[1]);
+seedable_rng!(KISS, 4, |s| s[0], s[1], s[2], s[3]);
+seedable_rng!(KISS2, 4, |s| s[0], s[1], s[2], s[3]);
+seedable_rng!(LFSR88, 3, |s| s[0], s[1], s[2]);
+seedable_rng!(LFSR113, 4, |s| s[0], s[1], s[2], s[3]);
+
+/// Matrices M^(2^k) of a GF(2) linear generator, for k from 0 to `D - 1`, where M is the matrix
+/// of one step. They are computed at compile time by repeated squaring.
+const fn pow2_matrices<const D: usize>(matrix_array: &[u32; 32]) -> [BitMatrix32; D] {
+    const ZERO: BitMatrix32 = BitMatrix32::new(&[0; 32]);
+    let mut matrices = [ZERO; D];
+    let mut matrix = BitMatrix32::new(matrix_array);
+    let mut k = 0;
+    while k < D {
+        let squared = matrix.const_dot(&matrix);
+        matrices[k] = matrix;
+        matrix = squared;
+        k += 1;
+    }
+    matrices
+}
+
+/// Jump a GF(2) linear generator state `z` ahead by 2^k steps, from its table of M^(2^j).
+///
+/// For a table of D matrices, the cycle length must be 2^D-1. Then 2^D = 1 modulo the cycle
+/// length, so 2^k steps is the same as 2^(k mod D) steps.
+fn matrix_jumped_pow2(z: u32, pow2_matrices: &[BitMatrix32], k: u32) -> u32 {
+    pow2_matrices[k as usize % pow2_matrices.len()].dot_vec(z)
+}
+
+/// Verify the period of a linear generator, from the matrix of one step.
+///
+/// The minimal polynomial of the matrix must be x^k·p(x), with p(x) primitive of degree d, and
+/// `cycle_len` must be 2^d-1. Then every state that isn't reduced to zero has period `cycle_len`.
+/// The period is also checked directly as the order of the matrix.
+fn verify_matrix_period(matrix_array: &[u32; 32], cycle_len: u32) -> bool {
+    let matrix = BitMatrix32::new(matrix_array);
+    let minimal_polynomial = matrix.minimal_polynomial();
+    let primitive_factor = minimal_polynomial >> minimal_polynomial.trailing_zeros();
+    let degree = gf2poly::degree(primitive_factor).unwrap_or(0);
+    gf2poly::is_primitive(primitive_factor)
+        && (1_u128 << degree) - 1 == cycle_len as u128
+        && matrix.verify_period(cycle_len as u128)
+}
+
 /* Cong ----------------------------------------------------------------------*/
 
 /// Cong -- Congruential random number generator
@@ -121,7 +353,7 @@ type BitMatrix32 = bitmatrix::BitMatrix<u32, 32>;
 /// of the most widely used generators of the last 30 years, as it was the system generator for VAX
 /// and was incorporated in several popular software packages, all seemingly without complaint.
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub struct Cong {
     cong: u32,
 }
@@ -131,9 +363,38 @@ impl Cong {
     const C: u32 = 12345;
     const CYCLE_LEN: u64 = 1 << 32;
 
-    pub fn new(seed1: u32) -> Cong {
+    /// Period of the generator, for any seeds
+    pub const PERIOD: u128 = Cong::CYCLE_LEN as u128;
+
+    pub const fn new(seed1: u32) -> Cong {
         Cong { cong: seed1 }
     }
+
+    /// Create a generator, or report a degenerate seed. See [`SHR3::try_new()`].
+    ///
+    /// Every `Cong` seed is valid, so this always succeeds.
+    pub fn try_new(seed1: u32) -> Result<Cong, SeedError> {
+        Ok(Cong::new(seed1))
+    }
+
+    /// The generator in canonical form
+    ///
+    /// Every `Cong` state is already canonical. See [`SHR3::canonical()`].
+    pub const fn canonical(&self) -> Cong {
+        Cong { cong: self.cong }
+    }
+
+    /// Jump ahead by `n` steps, returning the new generator.
+    ///
+    /// This is a `const fn` version of `jumpahead()`, so a fixed jump can be done at compile time.
+    pub const fn jumped(self, n: i128) -> Cong {
+        let n_mod = n.rem_euclid(Cong::CYCLE_LEN as i128) as u128;
+        let mult_exp = math::wrapping_pow_u32(Cong::M, n_mod);
+        let add_const = math::wrapping_geom_series_u32(Cong::M, n_mod).wrapping_mul(Cong::C);
+        Cong {
+            cong: mult_exp.wrapping_mul(self.cong).wrapping_add(add_const),
+        }
+    }
 }
 impl RngCore for Cong {
     fn next_u32(&mut self) -> u32 {
@@ -147,13 +408,14 @@ impl RngCore for Cong {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for Cong {
     fn jumpahead<N>(&mut self, n: N)
     where
-        N: math::IntTypes,
+        N: math::JumpCount,
     {
         let n_mod = math::modulo(n, Cong::CYCLE_LEN);
         let mult_exp = math::wrapping_pow(Cong::M, n_mod);
@@ -161,6 +423,17 @@ impl RngJumpAhead for Cong {
         let cong = mult_exp.wrapping_mul(self.cong).wrapping_add(add_const);
         self.cong = cong;
     }
+    fn jumpahead_pow2(&mut self, k: u32) {
+        // Doubling the jump composes x -> a·x + c with itself, giving a² and c·(a + 1). After
+        // 32 doublings, it's a jump by the whole period, which changes nothing.
+        let mut mult_exp = Cong::M;
+        let mut add_const = Cong::C;
+        for _ in 0..k.min(32) {
+            add_const = add_const.wrapping_mul(mult_exp.wrapping_add(1));
+            mult_exp = mult_exp.wrapping_mul(mult_exp);
+        }
+        self.cong = mult_exp.wrapping_mul(self.cong).wrapping_add(add_const);
+    }
 }
 
 /* SHR3 ----------------------------------------------------------------------*/
@@ -181,22 +454,102 @@ impl RngJumpAhead for Cong {
 /// successive values, as binary vectors, must be linearly independent, while 32 successive truly
 /// random 32-bit integers, viewed as binary vectors, will be linearly independent only about 29%
 /// of the time.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct SHR3 {
     shr3: u32,
 }
 
 impl SHR3 {
     const CYCLE_LEN: u32 = 0xFFFFFFFF;
+    /// Matrix of one step of the generator.
+    const MATRIX_ARRAY: [u32; 32] = [
+        0x00042021, 0x00084042, 0x00108084, 0x00210108, 0x00420231, 0x00840462, 0x010808C4,
+        0x02101188, 0x04202310, 0x08404620, 0x10808C40, 0x21011880, 0x42023100, 0x84046200,
+        0x0808C400, 0x10118800, 0x20231000, 0x40462021, 0x808C4042, 0x01080084, 0x02100108,
+        0x04200210, 0x08400420, 0x10800840, 0x21001080, 0x42002100, 0x84004200, 0x08008400,
+        0x10010800, 0x20021000, 0x40042000, 0x80084000,
+    ];
 
-    pub fn new(seed1: u32) -> SHR3 {
+    /// Period of the generator, for any seeds
+    pub const PERIOD: u128 = SHR3::CYCLE_LEN as u128;
+
+    pub const fn new(seed1: u32) -> SHR3 {
         SHR3 { shr3: seed1 }
     }
-    fn sanitise(&mut self) {
+
+    /// Create a generator, or report a degenerate seed
+    ///
+    /// `new()` accepts any seed, and a zero state is replaced by 0xFFFFFFFF when the generator is
+    /// used, since zero would be a fixed point. This returns a [`SeedError`] instead, naming the
+    /// seed and its replacement.
+    pub fn try_new(seed1: u32) -> Result<SHR3, SeedError> {
+        let rng = SHR3::new(seed1);
+        let error = rng.seed_error("SHR3", 1);
+        seed_result(rng, error)
+    }
+    /// The error for `try_new()` of a generator containing this one, with the state from `seed`.
+    fn seed_error(&self, generator: &'static str, seed: usize) -> Option<SeedError> {
+        if self.shr3 == 0 {
+            Some(SeedError {
+                generator,
+                component: "SHR3",
+                seed,
+                value: 0,
+                replacement: Some(0xFFFFFFFF),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The generator in canonical form
+    ///
+    /// Some states are replaced when the generator is used: a zero state becomes 0xFFFFFFFF, since
+    /// zero would be a fixed point. The canonical form has that done up front, so it produces the
+    /// same output as `self`. `PartialEq` and `Hash` use it, so generators that will produce the
+    /// same output compare equal.
+    pub const fn canonical(&self) -> SHR3 {
+        let mut result = SHR3 { shr3: self.shr3 };
+        result.sanitise();
+        result
+    }
+
+    /// Jump ahead by `n` steps, returning the new generator.
+    ///
+    /// This is a `const fn` version of `jumpahead()`, so a fixed jump can be done at compile time.
+    pub const fn jumped(mut self, n: i128) -> SHR3 {
+        let n_mod = n.rem_euclid(SHR3::CYCLE_LEN as i128) as u128;
+        self.sanitise();
+        let shr3_mult = BitMatrix32::new(&SHR3::MATRIX_ARRAY).const_pow(n_mod);
+        SHR3 {
+            shr3: shr3_mult.const_dot_vec(self.shr3),
+        }
+    }
+    const fn sanitise(&mut self) {
         if self.shr3 == 0 {
             self.shr3 = 0xFFFFFFFF;
         }
     }
+
+    /// Verify the period of the generator, from the matrix of one step.
+    ///
+    /// Checks that the minimal polynomial of the matrix is primitive, of degree 32, and that the
+    /// matrix has order 2^32-1. This checks the constants built into the crate, rather than
+    /// taking the period on trust.
+    pub fn verify_period() -> bool {
+        verify_matrix_period(&SHR3::MATRIX_ARRAY, SHR3::CYCLE_LEN)
+    }
+}
+impl PartialEq for SHR3 {
+    fn eq(&self, other: &SHR3) -> bool {
+        self.canonical().shr3 == other.canonical().shr3
+    }
+}
+impl Eq for SHR3 {}
+impl Hash for SHR3 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical().shr3.hash(state);
+    }
 }
 impl RngCore for SHR3 {
     fn next_u32(&mut self) -> u32 {
@@ -217,29 +570,29 @@ impl RngCore for SHR3 {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for SHR3 {
     fn jumpahead<N>(&mut self, n: N)
     where
-        N: math::IntTypes,
+        N: math::JumpCount,
     {
-        const SHR3_MATRIX_ARRAY: [u32; 32] = [
-            0x00042021, 0x00084042, 0x00108084, 0x00210108, 0x00420231, 0x00840462, 0x010808C4,
-            0x02101188, 0x04202310, 0x08404620, 0x10808C40, 0x21011880, 0x42023100, 0x84046200,
-            0x0808C400, 0x10118800, 0x20231000, 0x40462021, 0x808C4042, 0x01080084, 0x02100108,
-            0x04200210, 0x08400420, 0x10800840, 0x21001080, 0x42002100, 0x84004200, 0x08008400,
-            0x10010800, 0x20021000, 0x40042000, 0x80084000,
-        ];
         let n_mod = math::modulo(n, SHR3::CYCLE_LEN);
         self.sanitise();
-        let shr3_matrix = BitMatrix32::new(&SHR3_MATRIX_ARRAY);
+        let shr3_matrix = BitMatrix32::new(&SHR3::MATRIX_ARRAY);
         let shr3_mult = shr3_matrix.pow(n_mod);
         self.shr3 = shr3_mult.dot_vec(self.shr3);
     }
+    fn jumpahead_pow2(&mut self, k: u32) {
+        self.sanitise();
+        self.shr3 = matrix_jumped_pow2(self.shr3, &SHR3_POW2_MATRICES, k);
+    }
 }
 
+static SHR3_POW2_MATRICES: [BitMatrix32; 32] = pow2_matrices(&SHR3::MATRIX_ARRAY);
+
 /* MWC2 ----------------------------------------------------------------------*/
 
 /// MWC2 -- "Multiply-with-carry" random number generator
@@ -249,7 +602,7 @@ impl RngJumpAhead for SHR3 {
 ///
 /// This gets much better test results than MWC1 in L'Ecuyer's TestU01 test suite, so it should
 /// probably be preferred.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct MWC2 {
     upper: u32,
     lower: u32,
@@ -267,21 +620,85 @@ where
         .wrapping_add(&(x >> half_width_bits))
 }
 
-fn mwc_sanitise<T>(x: T, limit: T) -> T
+// Concrete-type functions, so they can be used in `const fn`.
+macro_rules! mwc_sanitise_fn {
+    ($name:ident, $canonical_name:ident, $error_name:ident, $t:ty) => {
+        const fn $name(x: $t, limit: $t) -> $t {
+            let mut temp = x;
+            if temp >= limit {
+                temp -= limit;
+            }
+            if temp == 0 {
+                temp = x ^ <$t>::MAX;
+                if temp >= limit {
+                    temp -= limit;
+                }
+            }
+            temp
+        }
+
+        /// A state which sanitises to the same value as `x`, and is its own canonical form.
+        ///
+        /// That's usually the sanitised value. But sanitising subtracts the limit only once, so
+        /// for a large `x` the result may still be at least the limit, and sanitising it again
+        /// would change it. Then the sanitised value plus the limit is used instead.
+        const fn $canonical_name(x: $t, limit: $t) -> $t {
+            let temp = $name(x, limit);
+            if temp < limit {
+                temp
+            } else {
+                temp + limit
+            }
+        }
+
+        /// The error for `try_new()` if `x` is a multiple of the limit, which is a fixed point.
+        ///
+        /// Sanitising only replaces zero and the limit itself, so a larger multiple stays a fixed
+        /// point, and there is no replacement.
+        fn $error_name(
+            generator: &'static str,
+            component: &'static str,
+            seed: usize,
+            x: $t,
+            limit: $t,
+        ) -> Option<SeedError> {
+            if x % limit != 0 {
+                return None;
+            }
+            let replacement = $name(x, limit);
+            Some(SeedError {
+                generator,
+                component,
+                seed,
+                value: x as u64,
+                replacement: if replacement % limit != 0 {
+                    Some(replacement as u64)
+                } else {
+                    None
+                },
+            })
+        }
+    };
+}
+
+mwc_sanitise_fn!(mwc_sanitise_u32, mwc_canonical_u32, mwc_seed_error_u32, u32);
+mwc_sanitise_fn!(mwc_sanitise_u64, mwc_canonical_u64, mwc_seed_error_u64, u64);
+
+/// `multiplier^(2^k)` modulo `modulus`, where `cycle_len` is a multiple of the order of
+/// `multiplier`.
+///
+/// For `k` less than the bit width, by squaring `k` times. Otherwise 2^k is first reduced modulo
+/// `cycle_len`.
+fn mwc_pow2_multiplier<T>(multiplier: T, k: u32, modulus: T, cycle_len: T) -> T
 where
-    T: PrimInt + Unsigned + SubAssign,
+    T: math::UIntTypes,
 {
-    let mut temp = x;
-    if temp >= limit {
-        temp -= limit;
-    }
-    if temp == T::zero() {
-        temp = x ^ T::max_value();
-        if temp >= limit {
-            temp -= limit;
-        }
+    if (k as usize) < math::size_of_bits::<T>() {
+        (0..k).fold(multiplier, |x, _| math::mul_mod(x, x, modulus))
+    } else {
+        let n_mod = math::pow_mod(T::ONE + T::ONE, k, cycle_len);
+        math::pow_mod(multiplier, n_mod, modulus)
     }
-    temp
 }
 
 impl MWC2 {
@@ -292,21 +709,118 @@ impl MWC2 {
     const UPPER_CYCLE_LEN: u32 = (MWC2::UPPER_M << 16) / 2 - 1;
     const LOWER_CYCLE_LEN: u32 = (MWC2::LOWER_M << 16) / 2 - 1;
 
-    pub fn new(seed1: u32, seed2: u32) -> MWC2 {
+    /// Period of the generator, for non-degenerate seeds
+    ///
+    /// A seed that makes a component state a multiple of its modulus, other than zero or the
+    /// modulus itself, leaves that component stuck at a fixed point. See [`MWC2::try_new()`].
+    pub const PERIOD: u128 = lcm_u128(MWC2::UPPER_CYCLE_LEN as u128, MWC2::LOWER_CYCLE_LEN as u128);
+
+    pub const fn new(seed1: u32, seed2: u32) -> MWC2 {
         MWC2 {
             upper: seed1,
             lower: seed2,
         }
     }
-    fn sanitise(&mut self) {
-        self.upper = mwc_sanitise(self.upper, MWC2::UPPER_MOD);
-        self.lower = mwc_sanitise(self.lower, MWC2::LOWER_MOD);
+
+    /// Create a generator, or report a degenerate seed. See [`SHR3::try_new()`].
+    ///
+    /// A component state that is a multiple of its modulus is a fixed point. `new()` replaces
+    /// zero or the modulus itself, but a larger multiple, only possible for the lower component,
+    /// stays stuck, and the error has no replacement.
+    pub fn try_new(seed1: u32, seed2: u32) -> Result<MWC2, SeedError> {
+        let rng = MWC2::new(seed1, seed2);
+        let error = rng.seed_error("MWC2", 1);
+        seed_result(rng, error)
+    }
+    fn seed_error(&self, generator: &'static str, seed: usize) -> Option<SeedError> {
+        mwc_seed_error_u32(generator, "MWC2 upper", seed, self.upper, MWC2::UPPER_MOD).or(
+            mwc_seed_error_u32(
+                generator,
+                "MWC2 lower",
+                seed + 1,
+                self.lower,
+                MWC2::LOWER_MOD,
+            ),
+        )
+    }
+
+    /// The generator in canonical form
+    ///
+    /// Each component state is sanitised as it would be for use: zero and a state equal to the
+    /// modulus are replaced, and the modulus is subtracted from a larger state. The result
+    /// produces the same output as `self`. (If a state is still at least the modulus after that,
+    /// the canonical state is the one above it that sanitises to the same value.)
+    ///
+    /// `PartialEq` and `Hash` go a step further, and compare the states after one step, since
+    /// a few states which differ by a multiple of the modulus still produce the same output.
+    pub const fn canonical(&self) -> MWC2 {
+        MWC2 {
+            upper: mwc_canonical_u32(self.upper, MWC2::UPPER_MOD),
+            lower: mwc_canonical_u32(self.lower, MWC2::LOWER_MOD),
+        }
+    }
+    /// The component states after one step, which determine all the output.
+    fn next_states(&self) -> (u32, u32) {
+        (
+            mwc_next(mwc_sanitise_u32(self.upper, MWC2::UPPER_MOD), MWC2::UPPER_M),
+            mwc_next(mwc_sanitise_u32(self.lower, MWC2::LOWER_MOD), MWC2::LOWER_M),
+        )
+    }
+
+    /// Jump ahead by `n` steps, returning the new generator.
+    ///
+    /// This is a `const fn` version of `jumpahead()`, so a fixed jump can be done at compile time.
+    pub const fn jumped(mut self, n: i128) -> MWC2 {
+        let n_upper = n.rem_euclid(MWC2::UPPER_CYCLE_LEN as i128) as u128;
+        let n_lower = n.rem_euclid(MWC2::LOWER_CYCLE_LEN as i128) as u128;
+
+        self.sanitise();
+        MWC2 {
+            upper: math::mul_mod_u32(
+                math::pow_mod_u32(MWC2::UPPER_M, n_upper, MWC2::UPPER_MOD),
+                self.upper,
+                MWC2::UPPER_MOD,
+            ),
+            lower: math::mul_mod_u32(
+                math::pow_mod_u32(MWC2::LOWER_M, n_lower, MWC2::LOWER_MOD),
+                self.lower,
+                MWC2::LOWER_MOD,
+            ),
+        }
+    }
+    const fn sanitise(&mut self) {
+        self.upper = mwc_sanitise_u32(self.upper, MWC2::UPPER_MOD);
+        self.lower = mwc_sanitise_u32(self.lower, MWC2::LOWER_MOD);
     }
     fn current(&self) -> u32 {
         self.lower
             .wrapping_add(self.upper << 16)
             .wrapping_add(self.upper >> 16)
     }
+
+    /// Verify the period of each component of the generator, from its multiplier.
+    ///
+    /// For each multiplier M, checks that `M·2^16 - 1` is a safe prime, and that 2^16 has order
+    /// `(M·2^16)/2 - 1` modulo it, so that component has that period as expected. The period of
+    /// the generator is the product of the component periods, since they are coprime.
+    pub fn verify_period() -> bool {
+        math::is_mwc_multiplier(MWC2::UPPER_M, 16)
+            && math::is_mwc_multiplier(MWC2::LOWER_M, 16)
+            && math::pow_mod(1 << 16, MWC2::UPPER_CYCLE_LEN, MWC2::UPPER_MOD) == 1
+            && math::pow_mod(1 << 16, MWC2::LOWER_CYCLE_LEN, MWC2::LOWER_MOD) == 1
+            && math::gcd(MWC2::UPPER_CYCLE_LEN, MWC2::LOWER_CYCLE_LEN) == 1
+    }
+}
+impl PartialEq for MWC2 {
+    fn eq(&self, other: &MWC2) -> bool {
+        self.next_states() == other.next_states()
+    }
+}
+impl Eq for MWC2 {}
+impl Hash for MWC2 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.next_states().hash(state);
+    }
 }
 impl RngCore for MWC2 {
     fn next_u32(&mut self) -> u32 {
@@ -323,13 +837,14 @@ impl RngCore for MWC2 {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for MWC2 {
     fn jumpahead<N>(&mut self, n: N)
     where
-        N: math::IntTypes,
+        N: math::JumpCount,
     {
         let n_upper = math::modulo(n, MWC2::UPPER_CYCLE_LEN);
         let n_lower = math::modulo(n, MWC2::LOWER_CYCLE_LEN);
@@ -346,6 +861,19 @@ impl RngJumpAhead for MWC2 {
             MWC2::LOWER_MOD,
         );
     }
+    fn jumpahead_pow2(&mut self, k: u32) {
+        self.sanitise();
+        self.upper = math::mul_mod(
+            mwc_pow2_multiplier(MWC2::UPPER_M, k, MWC2::UPPER_MOD, MWC2::UPPER_CYCLE_LEN),
+            self.upper,
+            MWC2::UPPER_MOD,
+        );
+        self.lower = math::mul_mod(
+            mwc_pow2_multiplier(MWC2::LOWER_M, k, MWC2::LOWER_MOD, MWC2::LOWER_CYCLE_LEN),
+            self.lower,
+            MWC2::LOWER_MOD,
+        );
+    }
 }
 
 /* MWC1 ----------------------------------------------------------------------*/
@@ -367,17 +895,43 @@ impl RngJumpAhead for MWC2 {
 /// This seems to pass all Marsaglia's Diehard tests. However, it fails many of L'Ecuyer's TestU01
 /// tests. The modified MWC2 generator passes many more tests in TestU01, and should probably be
 /// preferred, unless backwards compatibility is required.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub struct MWC1 {
     mwc: MWC2,
 }
 
 impl MWC1 {
-    pub fn new(seed1: u32, seed2: u32) -> MWC1 {
+    /// Period of the generator, for non-degenerate seeds. See [`MWC2::PERIOD`].
+    pub const PERIOD: u128 = MWC2::PERIOD;
+
+    pub const fn new(seed1: u32, seed2: u32) -> MWC1 {
         MWC1 {
             mwc: MWC2::new(seed1, seed2),
         }
     }
+
+    /// Create a generator, or report a degenerate seed. See [`MWC2::try_new()`].
+    pub fn try_new(seed1: u32, seed2: u32) -> Result<MWC1, SeedError> {
+        let rng = MWC1::new(seed1, seed2);
+        let error = rng.mwc.seed_error("MWC1", 1);
+        seed_result(rng, error)
+    }
+
+    /// The generator in canonical form. See [`MWC2::canonical()`].
+    pub const fn canonical(&self) -> MWC1 {
+        MWC1 {
+            mwc: self.mwc.canonical(),
+        }
+    }
+
+    /// Jump ahead by `n` steps, returning the new generator.
+    ///
+    /// This is a `const fn` version of `jumpahead()`, so a fixed jump can be done at compile time.
+    pub const fn jumped(self, n: i128) -> MWC1 {
+        MWC1 {
+            mwc: self.mwc.jumped(n),
+        }
+    }
     fn current(&self) -> u32 {
         self.mwc.lower.wrapping_add(self.mwc.upper << 16)
     }
@@ -394,16 +948,20 @@ impl RngCore for MWC1 {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for MWC1 {
     fn jumpahead<N>(&mut self, n: N)
     where
-        N: math::IntTypes,
+        N: math::JumpCount,
     {
         self.mwc.jumpahead(n);
     }
+    fn jumpahead_pow2(&mut self, k: u32) {
+        self.mwc.jumpahead_pow2(k);
+    }
 }
 
 /* KISS ----------------------------------------------------------------------*/
@@ -416,7 +974,7 @@ impl RngJumpAhead for MWC1 {
 /// newsgroup post. That generator most significantly has problems with its SHR3 component (see
 /// notes on SHR3). Since we are not keeping compatibility with the 1999 KISS generator for that
 /// reason, we take the opportunity to slightly update the MWC and Cong generators too.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub struct KISS {
     mwc: MWC2,
     cong: Cong,
@@ -424,13 +982,55 @@ pub struct KISS {
 }
 
 impl KISS {
-    pub fn new(seed1: u32, seed2: u32, seed3: u32, seed4: u32) -> KISS {
+    /// Period of the generator, for non-degenerate seeds. See [`MWC2::PERIOD`].
+    pub const PERIOD: u128 = lcm_u128(lcm_u128(MWC2::PERIOD, Cong::PERIOD), SHR3::PERIOD);
+
+    pub const fn new(seed1: u32, seed2: u32, seed3: u32, seed4: u32) -> KISS {
         KISS {
             mwc: MWC2::new(seed1, seed2),
             cong: Cong::new(seed3),
             shr3: SHR3::new(seed4),
         }
     }
+
+    /// Create a generator, or report the first degenerate seed of any component. See
+    /// [`MWC2::try_new()`] and [`SHR3::try_new()`].
+    pub fn try_new(seed1: u32, seed2: u32, seed3: u32, seed4: u32) -> Result<KISS, SeedError> {
+        let rng = KISS::new(seed1, seed2, seed3, seed4);
+        let error = rng
+            .mwc
+            .seed_error("KISS", 1)
+            .or(rng.shr3.seed_error("KISS", 4));
+        seed_result(rng, error)
+    }
+
+    /// The generator in canonical form, with each of its components in canonical form
+    pub const fn canonical(&self) -> KISS {
+        KISS {
+            mwc: self.mwc.canonical(),
+            cong: self.cong.canonical(),
+            shr3: self.shr3.canonical(),
+        }
+    }
+
+    /// Jump ahead by `n` steps, returning the new generator.
+    ///
+    /// This is a `const fn` version of `jumpahead()`, so a fixed jump can be done at compile time.
+    ///
+    ///     use ssrand::{RngJumpAhead, KISS};
+    ///     const KISS_2_40: KISS = KISS::new(1, 2, 3, 4).jumped(1 << 40);
+    ///
+    ///     let mut rng = KISS::new(1, 2, 3, 4);
+    ///     rng.jumpahead(1_u64 << 40);
+    ///     assert_eq!(rng, KISS_2_40);
+    ///
+    pub const fn jumped(self, n: i128) -> KISS {
+        KISS {
+            mwc: self.mwc.jumped(n),
+            cong: self.cong.jumped(n),
+            shr3: self.shr3.jumped(n),
+        }
+    }
     fn current(&self) -> u32 {
         (self.mwc.current() ^ self.cong.cong).wrapping_add(self.shr3.shr3)
     }
@@ -450,18 +1050,24 @@ impl RngCore for KISS {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for KISS {
     fn jumpahead<N>(&mut self, n: N)
     where
-        N: math::IntTypes,
+        N: math::JumpCount,
     {
         self.mwc.jumpahead(n);
         self.cong.jumpahead(n);
         self.shr3.jumpahead(n);
     }
+    fn jumpahead_pow2(&mut self, k: u32) {
+        self.mwc.jumpahead_pow2(k);
+        self.cong.jumpahead_pow2(k);
+        self.shr3.jumpahead_pow2(k);
+    }
 }
 
 /* MWC64 ---------------------------------------------------------------------*/
@@ -472,7 +1078,7 @@ impl RngJumpAhead for KISS {
 ///
 /// This uses a single MWC generator with a 64-bit calculation to generate a 32-bit value. The
 /// seeds should still be 32-bit values.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct MWC64 {
     mwc: u64,
 }
@@ -482,13 +1088,61 @@ impl MWC64 {
     const MOD: u64 = (MWC64::M << 32) - 1;
     const CYCLE_LEN: u64 = (MWC64::M << 32) / 2 - 1;
 
-    pub fn new(seed1: u32, seed2: u32) -> MWC64 {
+    /// Period of the generator, for non-degenerate seeds
+    ///
+    /// A few seeds make the state a multiple of the modulus, larger than the modulus itself,
+    /// which leaves it stuck at a fixed point. See [`MWC64::try_new()`].
+    pub const PERIOD: u128 = MWC64::CYCLE_LEN as u128;
+
+    pub const fn new(seed1: u32, seed2: u32) -> MWC64 {
         MWC64 {
             mwc: (((seed1 as u64) << 32) ^ (seed2 as u64)),
         }
     }
-    fn sanitise(&mut self) {
-        self.mwc = mwc_sanitise(self.mwc, MWC64::MOD);
+
+    /// Create a generator, or report a degenerate seed. See [`MWC2::try_new()`].
+    ///
+    /// The state is made from both seeds, so the error names `seed1`.
+    pub fn try_new(seed1: u32, seed2: u32) -> Result<MWC64, SeedError> {
+        let rng = MWC64::new(seed1, seed2);
+        let error = rng.seed_error("MWC64", 1);
+        seed_result(rng, error)
+    }
+    fn seed_error(&self, generator: &'static str, seed: usize) -> Option<SeedError> {
+        mwc_seed_error_u64(generator, "MWC64", seed, self.mwc, MWC64::MOD)
+    }
+
+    /// The generator in canonical form
+    ///
+    /// The state is sanitised as it would be for use, as for [`MWC2::canonical()`]. The result
+    /// produces the same output as `self`. `PartialEq` and `Hash` compare the states after one
+    /// step.
+    pub const fn canonical(&self) -> MWC64 {
+        MWC64 {
+            mwc: mwc_canonical_u64(self.mwc, MWC64::MOD),
+        }
+    }
+    /// The state after one step, which determines all the output.
+    fn next_state(&self) -> u64 {
+        mwc_next(mwc_sanitise_u64(self.mwc, MWC64::MOD), MWC64::M)
+    }
+
+    /// Jump ahead by `n` steps, returning the new generator.
+    ///
+    /// This is a `const fn` version of `jumpahead()`, so a fixed jump can be done at compile time.
+    pub const fn jumped(mut self, n: i128) -> MWC64 {
+        let n_mod = n.rem_euclid(MWC64::CYCLE_LEN as i128) as u128;
+        self.sanitise();
+        MWC64 {
+            mwc: math::mul_mod_u64(
+                math::pow_mod_u64(MWC64::M, n_mod, MWC64::MOD),
+                self.mwc,
+                MWC64::MOD,
+            ),
+        }
+    }
+    const fn sanitise(&mut self) {
+        self.mwc = mwc_sanitise_u64(self.mwc, MWC64::MOD);
     }
     fn next_mwc(&mut self) {
         self.mwc = mwc_next(self.mwc, MWC64::M);
@@ -496,6 +1150,26 @@ impl MWC64 {
     fn current(&self) -> u32 {
         self.mwc as u32
     }
+
+    /// Verify the period of the generator, from its multiplier.
+    ///
+    /// Checks that `M·2^32 - 1` is a safe prime, and that 2^32 has order `(M·2^32)/2 - 1` modulo
+    /// it, so the generator has that period as expected.
+    pub fn verify_period() -> bool {
+        math::is_mwc_multiplier(MWC64::M, 32)
+            && math::pow_mod(1 << 32, MWC64::CYCLE_LEN, MWC64::MOD) == 1
+    }
+}
+impl PartialEq for MWC64 {
+    fn eq(&self, other: &MWC64) -> bool {
+        self.next_state() == other.next_state()
+    }
+}
+impl Eq for MWC64 {}
+impl Hash for MWC64 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.next_state().hash(state);
+    }
 }
 impl RngCore for MWC64 {
     fn next_u32(&mut self) -> u32 {
@@ -512,13 +1186,14 @@ impl RngCore for MWC64 {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for MWC64 {
     fn jumpahead<N>(&mut self, n: N)
     where
-        N: math::IntTypes,
+        N: math::JumpCount,
     {
         let n_mod = math::modulo(n, MWC64::CYCLE_LEN);
         self.sanitise();
@@ -528,6 +1203,14 @@ impl RngJumpAhead for MWC64 {
             MWC64::MOD,
         );
     }
+    fn jumpahead_pow2(&mut self, k: u32) {
+        self.sanitise();
+        self.mwc = math::mul_mod(
+            mwc_pow2_multiplier(MWC64::M, k, MWC64::MOD, MWC64::CYCLE_LEN),
+            self.mwc,
+            MWC64::MOD,
+        );
+    }
 }
 
 /* KISS2 ---------------------------------------------------------------------*/
@@ -539,7 +1222,7 @@ impl RngJumpAhead for MWC64 {
 /// This is a slightly updated KISS generator design, from the newsgroup post in 2003. The MWC
 /// component uses a single 64-bit calculation, instead of two 32-bit calculations that are
 /// combined. The seeds should still be 32-bit values.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub struct KISS2 {
     mwc: MWC64,
     cong: Cong,
@@ -547,13 +1230,47 @@ pub struct KISS2 {
 }
 
 impl KISS2 {
-    pub fn new(seed1: u32, seed2: u32, seed3: u32, seed4: u32) -> KISS2 {
+    /// Period of the generator, for non-degenerate seeds. See [`MWC64::PERIOD`].
+    pub const PERIOD: u128 = lcm_u128(lcm_u128(MWC64::PERIOD, Cong::PERIOD), SHR3::PERIOD);
+
+    pub const fn new(seed1: u32, seed2: u32, seed3: u32, seed4: u32) -> KISS2 {
         KISS2 {
             mwc: MWC64::new(seed1, seed2),
             cong: Cong::new(seed3),
             shr3: SHR3::new(seed4),
         }
     }
+
+    /// Create a generator, or report the first degenerate seed of any component. See
+    /// [`MWC64::try_new()`] and [`SHR3::try_new()`].
+    pub fn try_new(seed1: u32, seed2: u32, seed3: u32, seed4: u32) -> Result<KISS2, SeedError> {
+        let rng = KISS2::new(seed1, seed2, seed3, seed4);
+        let error = rng
+            .mwc
+            .seed_error("KISS2", 1)
+            .or(rng.shr3.seed_error("KISS2", 4));
+        seed_result(rng, error)
+    }
+
+    /// The generator in canonical form, with each of its components in canonical form
+    pub const fn canonical(&self) -> KISS2 {
+        KISS2 {
+            mwc: self.mwc.canonical(),
+            cong: self.cong.canonical(),
+            shr3: self.shr3.canonical(),
+        }
+    }
+
+    /// Jump ahead by `n` steps, returning the new generator.
+    ///
+    /// This is a `const fn` version of `jumpahead()`, so a fixed jump can be done at compile time.
+    pub const fn jumped(self, n: i128) -> KISS2 {
+        KISS2 {
+            mwc: self.mwc.jumped(n),
+            cong: self.cong.jumped(n),
+            shr3: self.shr3.jumped(n),
+        }
+    }
     fn current(&self) -> u32 {
         self.mwc
             .current()
@@ -576,27 +1293,33 @@ impl RngCore for KISS2 {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for KISS2 {
     fn jumpahead<N>(&mut self, n: N)
     where
-        N: math::IntTypes,
+        N: math::JumpCount,
     {
         self.mwc.jumpahead(n);
         self.cong.jumpahead(n);
         self.shr3.jumpahead(n);
     }
+    fn jumpahead_pow2(&mut self, k: u32) {
+        self.mwc.jumpahead_pow2(k);
+        self.cong.jumpahead_pow2(k);
+        self.shr3.jumpahead_pow2(k);
+    }
 }
 
 /* LFSR ----------------------------------------------------------------------*/
 
-fn lfsr_seed_z(seed: u32) -> u32 {
+const fn lfsr_seed_z(seed: u32) -> u32 {
     seed ^ (seed << 16)
 }
 
-fn lfsr_sanitise_z(z: u32, min_value: u32) -> u32 {
+const fn lfsr_sanitise_z(z: u32, min_value: u32) -> u32 {
     if z < min_value {
         z ^ 0xFFFFFFFF
     } else {
@@ -604,12 +1327,48 @@ fn lfsr_sanitise_z(z: u32, min_value: u32) -> u32 {
     }
 }
 
-fn lfsr_next_z(z: u32, a: u8, b: u8, c: u8, min_value: u32) -> u32 {
+/// The error for `try_new()` if `z` is below `min_value`. Its bits are all shifted out without
+/// reaching the output, so the component would be stuck at zero.
+fn lfsr_seed_error(
+    generator: &'static str,
+    component: &'static str,
+    seed: usize,
+    z: u32,
+    min_value: u32,
+) -> Option<SeedError> {
+    if z < min_value {
+        Some(SeedError {
+            generator,
+            component,
+            seed,
+            value: z as u64,
+            replacement: Some(lfsr_sanitise_z(z, min_value) as u64),
+        })
+    } else {
+        None
+    }
+}
+
+/// Sanitise an LFSR component, and clear its bits below `min_value`. Those bits are shifted out
+/// by the next step without reaching the output.
+const fn lfsr_canonical_z(z: u32, min_value: u32) -> u32 {
+    lfsr_sanitise_z(z, min_value) & !(min_value - 1)
+}
+
+const fn lfsr_next_z(z: u32, a: u8, b: u8, c: u8, min_value: u32) -> u32 {
     let mask = 0xFFFFFFFF - (min_value - 1);
     let b = ((z << a) ^ z) >> b;
     ((z & mask) << c) ^ b
 }
 
+/// Jump one component of an LFSR generator ahead by `n` steps, at compile time if required.
+const fn lfsr_jumped_z(z: u32, matrix_array: &[u32; 32], n: i128, cycle_len: u32) -> u32 {
+    let n_mod = n.rem_euclid(cycle_len as i128) as u128;
+    BitMatrix32::new(matrix_array)
+        .const_pow(n_mod)
+        .const_dot_vec(z)
+}
+
 /* LFSR88 --------------------------------------------------------------------*/
 
 /// LFSR88 -- Combined LFSR random number generator by L'Ecuyer
@@ -621,7 +1380,7 @@ fn lfsr_next_z(z: u32, a: u8, b: u8, c: u8, min_value: u32) -> u32 {
 /// "Tables of Maximally-Equidistributed Combined Lfsr Generators"
 /// P. L'Ecuyer
 /// Mathematics of Computation, 68, 225 (1999), 261–269.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct LFSR88 {
     z1: u32,
     z2: u32,
@@ -635,21 +1394,87 @@ impl LFSR88 {
     const Z1_CYCLE_LEN: u32 = (1 << (32 - 1)) - 1;
     const Z2_CYCLE_LEN: u32 = (1 << (32 - 3)) - 1;
     const Z3_CYCLE_LEN: u32 = (1 << (32 - 4)) - 1;
+    /// Matrices of one step of each component.
+    const Z1_MATRIX_ARRAY: [u32; 32] = [
+        0x00000000, 0x00002000, 0x00004000, 0x00008000, 0x00010000, 0x00020000, 0x00040001,
+        0x00080002, 0x00100004, 0x00200008, 0x00400010, 0x00800020, 0x01000040, 0x02000080,
+        0x04000100, 0x08000200, 0x10000400, 0x20000800, 0x40001000, 0x80000001, 0x00000002,
+        0x00000004, 0x00000008, 0x00000010, 0x00000020, 0x00000040, 0x00000080, 0x00000100,
+        0x00000200, 0x00000400, 0x00000800, 0x00001000,
+    ];
+    const Z2_MATRIX_ARRAY: [u32; 32] = [
+        0x00000000, 0x00000000, 0x00000000, 0x00000080, 0x00000100, 0x00000200, 0x00000400,
+        0x00000800, 0x00001000, 0x00002000, 0x00004000, 0x00008000, 0x00010000, 0x00020000,
+        0x00040000, 0x00080000, 0x00100000, 0x00200000, 0x00400000, 0x00800000, 0x01000000,
+        0x02000000, 0x04000000, 0x08000001, 0x10000002, 0x20000005, 0x4000000A, 0x80000014,
+        0x00000028, 0x00000050, 0x00000020, 0x00000040,
+    ];
+    const Z3_MATRIX_ARRAY: [u32; 32] = [
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00200000, 0x00400000, 0x00800000,
+        0x01000000, 0x02000001, 0x04000002, 0x08000004, 0x10000009, 0x20000012, 0x40000024,
+        0x80000048, 0x00000090, 0x00000120, 0x00000240, 0x00000480, 0x00000900, 0x00001200,
+        0x00002400, 0x00004800, 0x00009000, 0x00012000, 0x00024000, 0x00048000, 0x00090000,
+        0x00120000, 0x00040000, 0x00080000, 0x00100000,
+    ];
 
-    pub fn new(seed1: u32, seed2: u32, seed3: u32) -> LFSR88 {
+    /// Period of the generator, for any seeds
+    pub const PERIOD: u128 = lcm_u128(
+        lcm_u128(LFSR88::Z1_CYCLE_LEN as u128, LFSR88::Z2_CYCLE_LEN as u128),
+        LFSR88::Z3_CYCLE_LEN as u128,
+    );
+
+    pub const fn new(seed1: u32, seed2: u32, seed3: u32) -> LFSR88 {
         LFSR88 {
             z1: lfsr_seed_z(seed1),
             z2: lfsr_seed_z(seed2),
             z3: lfsr_seed_z(seed3),
         }
     }
-    fn sanitise_z1(&mut self) {
+
+    /// Create a generator, or report a degenerate seed. See [`SHR3::try_new()`].
+    ///
+    /// A component state below its minimum value is degenerate, and `new()` inverts its bits. The
+    /// state is `seed ^ (seed << 16)`, so the error's value is that rather than the seed.
+    pub fn try_new(seed1: u32, seed2: u32, seed3: u32) -> Result<LFSR88, SeedError> {
+        let rng = LFSR88::new(seed1, seed2, seed3);
+        let error = lfsr_seed_error("LFSR88", "z1", 1, rng.z1, LFSR88::Z1_MIN)
+            .or(lfsr_seed_error("LFSR88", "z2", 2, rng.z2, LFSR88::Z2_MIN))
+            .or(lfsr_seed_error("LFSR88", "z3", 3, rng.z3, LFSR88::Z3_MIN));
+        seed_result(rng, error)
+    }
+    /// The generator in canonical form
+    ///
+    /// A component below its minimum value is replaced as for use, and the low bits of each
+    /// component, which never reach the output, are cleared. The result produces the same output
+    /// as `self`, and `PartialEq` and `Hash` use it.
+    pub const fn canonical(&self) -> LFSR88 {
+        LFSR88 {
+            z1: lfsr_canonical_z(self.z1, LFSR88::Z1_MIN),
+            z2: lfsr_canonical_z(self.z2, LFSR88::Z2_MIN),
+            z3: lfsr_canonical_z(self.z3, LFSR88::Z3_MIN),
+        }
+    }
+
+    /// Jump ahead by `n` steps, returning the new generator.
+    ///
+    /// This is a `const fn` version of `jumpahead()`, so a fixed jump can be done at compile time.
+    pub const fn jumped(mut self, n: i128) -> LFSR88 {
+        self.sanitise_z1();
+        self.sanitise_z2();
+        self.sanitise_z3();
+        LFSR88 {
+            z1: lfsr_jumped_z(self.z1, &LFSR88::Z1_MATRIX_ARRAY, n, LFSR88::Z1_CYCLE_LEN),
+            z2: lfsr_jumped_z(self.z2, &LFSR88::Z2_MATRIX_ARRAY, n, LFSR88::Z2_CYCLE_LEN),
+            z3: lfsr_jumped_z(self.z3, &LFSR88::Z3_MATRIX_ARRAY, n, LFSR88::Z3_CYCLE_LEN),
+        }
+    }
+    const fn sanitise_z1(&mut self) {
         self.z1 = lfsr_sanitise_z(self.z1, LFSR88::Z1_MIN);
     }
-    fn sanitise_z2(&mut self) {
+    const fn sanitise_z2(&mut self) {
         self.z2 = lfsr_sanitise_z(self.z2, LFSR88::Z2_MIN);
     }
-    fn sanitise_z3(&mut self) {
+    const fn sanitise_z3(&mut self) {
         self.z3 = lfsr_sanitise_z(self.z3, LFSR88::Z3_MIN);
     }
     fn next_z1(&mut self) {
@@ -664,6 +1489,30 @@ impl LFSR88 {
     fn current(&self) -> u32 {
         self.z1 ^ self.z2 ^ self.z3
     }
+
+    /// Verify the period of each component of the generator, from the matrix of one step.
+    ///
+    /// For each component, checks that the minimal polynomial of its matrix is x^k times a
+    /// primitive polynomial of degree d, and that the matrix has order 2^d-1 as expected. The
+    /// period of the generator is the product of the component periods, since they are coprime.
+    pub fn verify_period() -> bool {
+        verify_matrix_period(&LFSR88::Z1_MATRIX_ARRAY, LFSR88::Z1_CYCLE_LEN)
+            && verify_matrix_period(&LFSR88::Z2_MATRIX_ARRAY, LFSR88::Z2_CYCLE_LEN)
+            && verify_matrix_period(&LFSR88::Z3_MATRIX_ARRAY, LFSR88::Z3_CYCLE_LEN)
+    }
+}
+impl PartialEq for LFSR88 {
+    fn eq(&self, other: &LFSR88) -> bool {
+        let (a, b) = (self.canonical(), other.canonical());
+        (a.z1, a.z2, a.z3) == (b.z1, b.z2, b.z3)
+    }
+}
+impl Eq for LFSR88 {}
+impl Hash for LFSR88 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let a = self.canonical();
+        (a.z1, a.z2, a.z3).hash(state);
+    }
 }
 impl RngCore for LFSR88 {
     fn next_u32(&mut self) -> u32 {
@@ -683,56 +1532,47 @@ impl RngCore for LFSR88 {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for LFSR88 {
     fn jumpahead<N>(&mut self, n: N)
     where
-        N: math::IntTypes,
+        N: math::JumpCount,
     {
-        const LFSR88_Z1_MATRIX_ARRAY: [u32; 32] = [
-            0x00000000, 0x00002000, 0x00004000, 0x00008000, 0x00010000, 0x00020000, 0x00040001,
-            0x00080002, 0x00100004, 0x00200008, 0x00400010, 0x00800020, 0x01000040, 0x02000080,
-            0x04000100, 0x08000200, 0x10000400, 0x20000800, 0x40001000, 0x80000001, 0x00000002,
-            0x00000004, 0x00000008, 0x00000010, 0x00000020, 0x00000040, 0x00000080, 0x00000100,
-            0x00000200, 0x00000400, 0x00000800, 0x00001000,
-        ];
-        const LFSR88_Z2_MATRIX_ARRAY: [u32; 32] = [
-            0x00000000, 0x00000000, 0x00000000, 0x00000080, 0x00000100, 0x00000200, 0x00000400,
-            0x00000800, 0x00001000, 0x00002000, 0x00004000, 0x00008000, 0x00010000, 0x00020000,
-            0x00040000, 0x00080000, 0x00100000, 0x00200000, 0x00400000, 0x00800000, 0x01000000,
-            0x02000000, 0x04000000, 0x08000001, 0x10000002, 0x20000005, 0x4000000A, 0x80000014,
-            0x00000028, 0x00000050, 0x00000020, 0x00000040,
-        ];
-        const LFSR88_Z3_MATRIX_ARRAY: [u32; 32] = [
-            0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00200000, 0x00400000, 0x00800000,
-            0x01000000, 0x02000001, 0x04000002, 0x08000004, 0x10000009, 0x20000012, 0x40000024,
-            0x80000048, 0x00000090, 0x00000120, 0x00000240, 0x00000480, 0x00000900, 0x00001200,
-            0x00002400, 0x00004800, 0x00009000, 0x00012000, 0x00024000, 0x00048000, 0x00090000,
-            0x00120000, 0x00040000, 0x00080000, 0x00100000,
-        ];
-
         let n_z1 = math::modulo(n, LFSR88::Z1_CYCLE_LEN);
         self.sanitise_z1();
-        let lfsr88_matrix = BitMatrix32::new(&LFSR88_Z1_MATRIX_ARRAY);
+        let lfsr88_matrix = BitMatrix32::new(&LFSR88::Z1_MATRIX_ARRAY);
         let lfsr88_mult = lfsr88_matrix.pow(n_z1);
         self.z1 = lfsr88_mult.dot_vec(self.z1);
 
         let n_z2 = math::modulo(n, LFSR88::Z2_CYCLE_LEN);
         self.sanitise_z2();
-        let lfsr88_matrix = BitMatrix32::new(&LFSR88_Z2_MATRIX_ARRAY);
+        let lfsr88_matrix = BitMatrix32::new(&LFSR88::Z2_MATRIX_ARRAY);
         let lfsr88_mult = lfsr88_matrix.pow(n_z2);
         self.z2 = lfsr88_mult.dot_vec(self.z2);
 
         let n_z3 = math::modulo(n, LFSR88::Z3_CYCLE_LEN);
         self.sanitise_z3();
-        let lfsr88_matrix = BitMatrix32::new(&LFSR88_Z3_MATRIX_ARRAY);
+        let lfsr88_matrix = BitMatrix32::new(&LFSR88::Z3_MATRIX_ARRAY);
         let lfsr88_mult = lfsr88_matrix.pow(n_z3);
         self.z3 = lfsr88_mult.dot_vec(self.z3);
     }
+    fn jumpahead_pow2(&mut self, k: u32) {
+        self.sanitise_z1();
+        self.z1 = matrix_jumped_pow2(self.z1, &LFSR88_Z1_POW2_MATRICES, k);
+        self.sanitise_z2();
+        self.z2 = matrix_jumped_pow2(self.z2, &LFSR88_Z2_POW2_MATRICES, k);
+        self.sanitise_z3();
+        self.z3 = matrix_jumped_pow2(self.z3, &LFSR88_Z3_POW2_MATRICES, k);
+    }
 }
 
+static LFSR88_Z1_POW2_MATRICES: [BitMatrix32; 31] = pow2_matrices(&LFSR88::Z1_MATRIX_ARRAY);
+static LFSR88_Z2_POW2_MATRICES: [BitMatrix32; 29] = pow2_matrices(&LFSR88::Z2_MATRIX_ARRAY);
+static LFSR88_Z3_POW2_MATRICES: [BitMatrix32; 28] = pow2_matrices(&LFSR88::Z3_MATRIX_ARRAY);
+
 /* LFSR113 -------------------------------------------------------------------*/
 
 /// LFSR113 -- Combined LFSR random number generator by L'Ecuyer
@@ -744,7 +1584,7 @@ impl RngJumpAhead for LFSR88 {
 /// "Tables of Maximally-Equidistributed Combined Lfsr Generators"
 /// P. L'Ecuyer
 /// Mathematics of Computation, 68, 225 (1999), 261–269.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct LFSR113 {
     z1: u32,
     z2: u32,
@@ -761,8 +1601,43 @@ impl LFSR113 {
     const Z2_CYCLE_LEN: u32 = (1 << (32 - 3)) - 1;
     const Z3_CYCLE_LEN: u32 = (1 << (32 - 4)) - 1;
     const Z4_CYCLE_LEN: u32 = (1 << (32 - 7)) - 1;
+    /// Matrices of one step of each component.
+    const Z1_MATRIX_ARRAY: [u32; 32] = [
+        0x00000000, 0x00080000, 0x00100000, 0x00200000, 0x00400000, 0x00800000, 0x01000000,
+        0x02000001, 0x04000002, 0x08000004, 0x10000008, 0x20000010, 0x40000020, 0x80000041,
+        0x00000082, 0x00000104, 0x00000208, 0x00000410, 0x00000820, 0x00001040, 0x00002080,
+        0x00004100, 0x00008200, 0x00010400, 0x00020800, 0x00041000, 0x00002000, 0x00004000,
+        0x00008000, 0x00010000, 0x00020000, 0x00040000,
+    ];
+    const Z2_MATRIX_ARRAY: [u32; 32] = [
+        0x00000000, 0x00000000, 0x00000000, 0x00000020, 0x00000040, 0x00000080, 0x00000100,
+        0x00000200, 0x00000400, 0x00000800, 0x00001000, 0x00002000, 0x00004000, 0x00008000,
+        0x00010000, 0x00020000, 0x00040000, 0x00080000, 0x00100000, 0x00200000, 0x00400000,
+        0x00800000, 0x01000000, 0x02000000, 0x04000000, 0x08000001, 0x10000002, 0x20000005,
+        0x4000000A, 0x80000014, 0x00000008, 0x00000010,
+    ];
+    const Z3_MATRIX_ARRAY: [u32; 32] = [
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000800, 0x00001000, 0x00002000,
+        0x00004000, 0x00008001, 0x00010002, 0x00020004, 0x00040008, 0x00080010, 0x00100020,
+        0x00200040, 0x00400080, 0x00800100, 0x01000200, 0x02000400, 0x04000000, 0x08000000,
+        0x10000001, 0x20000002, 0x40000004, 0x80000008, 0x00000010, 0x00000020, 0x00000040,
+        0x00000080, 0x00000100, 0x00000200, 0x00000400,
+    ];
+    const Z4_MATRIX_ARRAY: [u32; 32] = [
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00100000, 0x00200000, 0x00400001, 0x00800002, 0x01000004, 0x02000009, 0x04000012,
+        0x08000024, 0x10000048, 0x20000090, 0x40000120, 0x80000240, 0x00000480, 0x00000900,
+        0x00001200, 0x00002400, 0x00004800, 0x00009000, 0x00012000, 0x00024000, 0x00048000,
+        0x00090000, 0x00020000, 0x00040000, 0x00080000,
+    ];
 
-    pub fn new(seed1: u32, seed2: u32, seed3: u32, seed4: u32) -> LFSR113 {
+    /// Period of the generator, for any seeds
+    pub const PERIOD: u128 = lcm_u128(
+        lcm_u128(LFSR113::Z1_CYCLE_LEN as u128, LFSR113::Z2_CYCLE_LEN as u128),
+        lcm_u128(LFSR113::Z3_CYCLE_LEN as u128, LFSR113::Z4_CYCLE_LEN as u128),
+    );
+
+    pub const fn new(seed1: u32, seed2: u32, seed3: u32, seed4: u32) -> LFSR113 {
         LFSR113 {
             z1: lfsr_seed_z(seed1),
             z2: lfsr_seed_z(seed2),
@@ -770,16 +1645,51 @@ impl LFSR113 {
             z4: lfsr_seed_z(seed4),
         }
     }
-    fn sanitise_z1(&mut self) {
+
+    /// Create a generator, or report a degenerate seed. See [`LFSR88::try_new()`].
+    pub fn try_new(seed1: u32, seed2: u32, seed3: u32, seed4: u32) -> Result<LFSR113, SeedError> {
+        let rng = LFSR113::new(seed1, seed2, seed3, seed4);
+        let error = lfsr_seed_error("LFSR113", "z1", 1, rng.z1, LFSR113::Z1_MIN)
+            .or(lfsr_seed_error("LFSR113", "z2", 2, rng.z2, LFSR113::Z2_MIN))
+            .or(lfsr_seed_error("LFSR113", "z3", 3, rng.z3, LFSR113::Z3_MIN))
+            .or(lfsr_seed_error("LFSR113", "z4", 4, rng.z4, LFSR113::Z4_MIN));
+        seed_result(rng, error)
+    }
+    /// The generator in canonical form. See [`LFSR88::canonical()`].
+    pub const fn canonical(&self) -> LFSR113 {
+        LFSR113 {
+            z1: lfsr_canonical_z(self.z1, LFSR113::Z1_MIN),
+            z2: lfsr_canonical_z(self.z2, LFSR113::Z2_MIN),
+            z3: lfsr_canonical_z(self.z3, LFSR113::Z3_MIN),
+            z4: lfsr_canonical_z(self.z4, LFSR113::Z4_MIN),
+        }
+    }
+
+    /// Jump ahead by `n` steps, returning the new generator.
+    ///
+    /// This is a `const fn` version of `jumpahead()`, so a fixed jump can be done at compile time.
+    pub const fn jumped(mut self, n: i128) -> LFSR113 {
+        self.sanitise_z1();
+        self.sanitise_z2();
+        self.sanitise_z3();
+        self.sanitise_z4();
+        LFSR113 {
+            z1: lfsr_jumped_z(self.z1, &LFSR113::Z1_MATRIX_ARRAY, n, LFSR113::Z1_CYCLE_LEN),
+            z2: lfsr_jumped_z(self.z2, &LFSR113::Z2_MATRIX_ARRAY, n, LFSR113::Z2_CYCLE_LEN),
+            z3: lfsr_jumped_z(self.z3, &LFSR113::Z3_MATRIX_ARRAY, n, LFSR113::Z3_CYCLE_LEN),
+            z4: lfsr_jumped_z(self.z4, &LFSR113::Z4_MATRIX_ARRAY, n, LFSR113::Z4_CYCLE_LEN),
+        }
+    }
+    const fn sanitise_z1(&mut self) {
         self.z1 = lfsr_sanitise_z(self.z1, LFSR113::Z1_MIN);
     }
-    fn sanitise_z2(&mut self) {
+    const fn sanitise_z2(&mut self) {
         self.z2 = lfsr_sanitise_z(self.z2, LFSR113::Z2_MIN);
     }
-    fn sanitise_z3(&mut self) {
+    const fn sanitise_z3(&mut self) {
         self.z3 = lfsr_sanitise_z(self.z3, LFSR113::Z3_MIN);
     }
-    fn sanitise_z4(&mut self) {
+    const fn sanitise_z4(&mut self) {
         self.z4 = lfsr_sanitise_z(self.z4, LFSR113::Z4_MIN);
     }
     fn next_z1(&mut self) {
@@ -797,6 +1707,31 @@ impl LFSR113 {
     fn current(&self) -> u32 {
         self.z1 ^ self.z2 ^ self.z3 ^ self.z4
     }
+
+    /// Verify the period of each component of the generator, from the matrix of one step.
+    ///
+    /// For each component, checks that the minimal polynomial of its matrix is x^k times a
+    /// primitive polynomial of degree d, and that the matrix has order 2^d-1 as expected. The
+    /// period of the generator is the product of the component periods, since they are coprime.
+    pub fn verify_period() -> bool {
+        verify_matrix_period(&LFSR113::Z1_MATRIX_ARRAY, LFSR113::Z1_CYCLE_LEN)
+            && verify_matrix_period(&LFSR113::Z2_MATRIX_ARRAY, LFSR113::Z2_CYCLE_LEN)
+            && verify_matrix_period(&LFSR113::Z3_MATRIX_ARRAY, LFSR113::Z3_CYCLE_LEN)
+            && verify_matrix_period(&LFSR113::Z4_MATRIX_ARRAY, LFSR113::Z4_CYCLE_LEN)
+    }
+}
+impl PartialEq for LFSR113 {
+    fn eq(&self, other: &LFSR113) -> bool {
+        let (a, b) = (self.canonical(), other.canonical());
+        (a.z1, a.z2, a.z3, a.z4) == (b.z1, b.z2, b.z3, b.z4)
+    }
+}
+impl Eq for LFSR113 {}
+impl Hash for LFSR113 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let a = self.canonical();
+        (a.z1, a.z2, a.z3, a.z4).hash(state);
+    }
 }
 impl RngCore for LFSR113 {
     fn next_u32(&mut self) -> u32 {
@@ -818,65 +1753,52 @@ impl RngCore for LFSR113 {
         impls::fill_bytes_via_next(self, dest)
     }
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 impl RngJumpAhead for LFSR113 {
     fn jumpahead<N>(&mut self, n: N)
     where
-        N: math::IntTypes,
+        N: math::JumpCount,
     {
-        const LFSR113_Z1_MATRIX_ARRAY: [u32; 32] = [
-            0x00000000, 0x00080000, 0x00100000, 0x00200000, 0x00400000, 0x00800000, 0x01000000,
-            0x02000001, 0x04000002, 0x08000004, 0x10000008, 0x20000010, 0x40000020, 0x80000041,
-            0x00000082, 0x00000104, 0x00000208, 0x00000410, 0x00000820, 0x00001040, 0x00002080,
-            0x00004100, 0x00008200, 0x00010400, 0x00020800, 0x00041000, 0x00002000, 0x00004000,
-            0x00008000, 0x00010000, 0x00020000, 0x00040000,
-        ];
-        const LFSR113_Z2_MATRIX_ARRAY: [u32; 32] = [
-            0x00000000, 0x00000000, 0x00000000, 0x00000020, 0x00000040, 0x00000080, 0x00000100,
-            0x00000200, 0x00000400, 0x00000800, 0x00001000, 0x00002000, 0x00004000, 0x00008000,
-            0x00010000, 0x00020000, 0x00040000, 0x00080000, 0x00100000, 0x00200000, 0x00400000,
-            0x00800000, 0x01000000, 0x02000000, 0x04000000, 0x08000001, 0x10000002, 0x20000005,
-            0x4000000A, 0x80000014, 0x00000008, 0x00000010,
-        ];
-        const LFSR113_Z3_MATRIX_ARRAY: [u32; 32] = [
-            0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000800, 0x00001000, 0x00002000,
-            0x00004000, 0x00008001, 0x00010002, 0x00020004, 0x00040008, 0x00080010, 0x00100020,
-            0x00200040, 0x00400080, 0x00800100, 0x01000200, 0x02000400, 0x04000000, 0x08000000,
-            0x10000001, 0x20000002, 0x40000004, 0x80000008, 0x00000010, 0x00000020, 0x00000040,
-            0x00000080, 0x00000100, 0x00000200, 0x00000400,
-        ];
-        const LFSR113_Z4_MATRIX_ARRAY: [u32; 32] = [
-            0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
-            0x00100000, 0x00200000, 0x00400001, 0x00800002, 0x01000004, 0x02000009, 0x04000012,
-            0x08000024, 0x10000048, 0x20000090, 0x40000120, 0x80000240, 0x00000480, 0x00000900,
-            0x00001200, 0x00002400, 0x00004800, 0x00009000, 0x00012000, 0x00024000, 0x00048000,
-            0x00090000, 0x00020000, 0x00040000, 0x00080000,
-        ];
-
         let n_z1 = math::modulo(n, LFSR113::Z1_CYCLE_LEN);
         self.sanitise_z1();
-        let lfsr113_matrix = BitMatrix32::new(&LFSR113_Z1_MATRIX_ARRAY);
+        let lfsr113_matrix = BitMatrix32::new(&LFSR113::Z1_MATRIX_ARRAY);
         let lfsr113_mult = lfsr113_matrix.pow(n_z1);
         self.z1 = lfsr113_mult.dot_vec(self.z1);
 
         let n_z2 = math::modulo(n, LFSR113::Z2_CYCLE_LEN);
         self.sanitise_z2();
-        let lfsr113_matrix = BitMatrix32::new(&LFSR113_Z2_MATRIX_ARRAY);
+        let lfsr113_matrix = BitMatrix32::new(&LFSR113::Z2_MATRIX_ARRAY);
         let lfsr113_mult = lfsr113_matrix.pow(n_z2);
         self.z2 = lfsr113_mult.dot_vec(self.z2);
 
         let n_z3 = math::modulo(n, LFSR113::Z3_CYCLE_LEN);
         self.sanitise_z3();
-        let lfsr113_matrix = BitMatrix32::new(&LFSR113_Z3_MATRIX_ARRAY);
+        let lfsr113_matrix = BitMatrix32::new(&LFSR113::Z3_MATRIX_ARRAY);
         let lfsr113_mult = lfsr113_matrix.pow(n_z3);
         self.z3 = lfsr113_mult.dot_vec(self.z3);
 
         let n_z4 = math::modulo(n, LFSR113::Z4_CYCLE_LEN);
         self.sanitise_z4();
-        let lfsr113_matrix = BitMatrix32::new(&LFSR113_Z4_MATRIX_ARRAY);
+        let lfsr113_matrix = BitMatrix32::new(&LFSR113::Z4_MATRIX_ARRAY);
         let lfsr113_mult = lfsr113_matrix.pow(n_z4);
         self.z4 = lfsr113_mult.dot_vec(self.z4);
     }
+    fn jumpahead_pow2(&mut self, k: u32) {
+        self.sanitise_z1();
+        self.z1 = matrix_jumped_pow2(self.z1, &LFSR113_Z1_POW2_MATRICES, k);
+        self.sanitise_z2();
+        self.z2 = matrix_jumped_pow2(self.z2, &LFSR113_Z2_POW2_MATRICES, k);
+        self.sanitise_z3();
+        self.z3 = matrix_jumped_pow2(self.z3, &LFSR113_Z3_POW2_MATRICES, k);
+        self.sanitise_z4();
+        self.z4 = matrix_jumped_pow2(self.z4, &LFSR113_Z4_POW2_MATRICES, k);
+    }
 }
+
+static LFSR113_Z1_POW2_MATRICES: [BitMatrix32; 31] = pow2_matrices(&LFSR113::Z1_MATRIX_ARRAY);
+static LFSR113_Z2_POW2_MATRICES: [BitMatrix32; 29] = pow2_matrices(&LFSR113::Z2_MATRIX_ARRAY);
+static LFSR113_Z3_POW2_MATRICES: [BitMatrix32; 28] = pow2_matrices(&LFSR113::Z3_MATRIX_ARRAY);
+static LFSR113_Z4_POW2_MATRICES: [BitMatrix32; 25] = pow2_matrices(&LFSR113::Z4_MATRIX_ARRAY);
@@ -1,57 +1,580 @@
-#![allow(dead_code)]
+//! `ssrand` command-line tool
+//!
+//! Prints the output of any of the generators for a given seed, optionally after a jump-ahead.
+//! It is used to produce reference values when porting the generators to other languages.
+//!
+//! The generator state is printed either in Rust `Debug` form, or as JSON derived from it.
+//!
+//! It can also write a raw binary stream of generator output to stdout, to be piped into external
+//! statistical test batteries such as PractRand or dieharder.
+//!
+//! The `mwc-search` command finds multipliers for custom MWC generators, eg for small MCUs.
+
+use std::fmt::Debug;
+use std::io::{self, Write};
+use std::process::ExitCode;
+use std::str::FromStr;
 
 use rand_core::RngCore;
+use ssrand::anyrng::{AnyRng, ParseRngError, GENERATORS};
+use ssrand::bounded::RngBounded;
+use ssrand::float::RngFloat;
+use ssrand::math::BigUint;
+use ssrand::seed::SeedSequence;
 use ssrand::RngJumpAhead;
-//use rand::Rng;
-
-fn test_new_and_next_u32() {
-    //let mut s = ssrand::Cong::new(1);
-    //let mut s = ssrand::SHR3::new(1);
-    //let mut s = ssrand::MWC1::new(1, 2);
-    //let mut s = ssrand::MWC2::new(1, 2);
-    //let mut s = ssrand::KISS::new(1, 2, 3, 4);
-    //let mut s = ssrand::MWC64::new(1, 2);
-    //let mut s = ssrand::KISS2::new(1, 2, 3, 4);
-    //let mut s = ssrand::LFSR88::new(1, 2, 3);
-    let mut s = ssrand::LFSR113::new(1, 2, 3, 4);
-    for _ in 0..4 {
-        println!("{}, {:?}", s.next_u32(), s);
-        //println!("{}, {:?}", s.gen::<u32>(), s);
-        //println!("{:?}", s.gen::<(f64)>());
-    }
-
-    let jumpahead_n = 1_000_000_000_000_000_000_i64;
-    s.jumpahead(jumpahead_n);
-    println!("jumpahead by {}", jumpahead_n);
-    println!("{}, {:?}", s.next_u32(), s);
-}
-
-fn test_maths() {
-    println!(
-        "wrapping_pow {}",
-        ssrand::math::wrapping_pow(123456789_u32, 123456789_u32)
-    );
-    println!(
-        "pow_mod {:#X}",
-        ssrand::math::pow_mod(0xDC28D76F_usize, 0x732E73C3_usize, 0xEC327D45_usize)
-    );
-    println!(
-        "pow_mod {:#X}",
-        ssrand::math::pow_mod(
-            0xDC28D76FFD9338E9D868AF566191DE10_u128,
-            0x732E73C316878E244FDFDE4EE623CDCC_u128,
-            0xEC327D45470669CC56B547B6FE6888A2_u128
+
+const USAGE: &str = "\
+Usage:
+    ssrand generate <GENERATOR> [SEED]... [OPTIONS]
+    ssrand stream <GENERATOR> [SEED]... [OPTIONS]
+    ssrand mwc-search <WORD_BITS> [OPTIONS]
+    ssrand list
+    ssrand help
+
+Generators (case-insensitive), with their number of seeds. `ssrand list` also shows periods:
+    Cong (1), SHR3 (1), MWC1 (2), MWC2 (2), MWC64 (2), KISS (4), KISS2 (4),
+    LFSR88 (3), LFSR113 (4)
+
+Seeds are 32-bit unsigned values, in decimal or hex with a 0x prefix.
+
+Common options:
+    -x, --expand <N>       Instead of giving the seeds, expand the 64-bit value N into
+                           all of the generator's seeds, with ssrand's SeedSequence.
+                           Degenerate seeds are skipped.
+    -j, --jump <N>         Jump ahead by N before generating. N may be negative, or larger
+                           than the generator's period, up to 2^256-1. In decimal, or hex
+                           with a 0x prefix.
+
+Generate options:
+    -n, --count <N>        Number of outputs to print [default: 4]
+    -f, --format <FMT>     Output format: dec, hex, float, float53 [default: dec]
+    -B, --below <N>        Print integers in [0, N) instead, by Lemire's method, in dec or
+                           hex
+    -s, --state <FMT>      Print the generator state after each output: debug, json
+
+The float format is one output times 2^-32, as in L'Ecuyer's lfsr113.c. The float53 format
+takes two outputs for each 53-bit value.
+
+Stream options:
+    -b, --bytes <N>        Stop after N bytes [default: endless]
+    -w, --word <BITS>      Word size: 8, 16, 32, 64 [default: 32]
+    -e, --endian <E>       Byte order of each word: little, big [default: little]
+    -i, --interleave <K>   Interleave words from K substreams [default: 1]
+    -t, --stride <N>       Jump-ahead distance between successive substreams. Required
+                           when interleaving.
+
+Stream words are taken from the generator's 32-bit outputs. A 64-bit word is two outputs,
+the first in the low half. 8-bit and 16-bit words are pieces of an output, low bits first.
+
+MWC search options:
+    -r, --lag <R>          Lag of the MWC, ie number of words of state [default: 1]
+    -c, --candidates <K>   Number of multipliers to consider, largest first [default: 100]
+    -n, --count <N>        Number of the best-scoring multipliers to print [default: 10]
+
+MWC search prints multipliers a for which a*2^(WORD_BITS*R)-1 is a safe prime, ranked by
+their worst spectral test score in dimensions 2 to 8. Each line is: multiplier, modulus,
+period, score.
+";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum OutputFormat {
+    Dec,
+    Hex,
+    Float,
+    Float53,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum StateFormat {
+    Debug,
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Endian {
+    Little,
+    Big,
+}
+
+/// A jump-ahead count: any `i128`, or a larger unsigned count.
+#[derive(Clone, Copy, Debug)]
+enum Jump {
+    Signed(i128),
+    Big(BigUint<4>),
+}
+
+impl Jump {
+    fn apply<R: RngJumpAhead>(self, rng: &mut R) {
+        match self {
+            Jump::Signed(n) => rng.jumpahead(n),
+            Jump::Big(n) => rng.jumpahead(n),
+        }
+    }
+}
+
+/// Generator selection, common to all commands.
+#[derive(Debug)]
+struct GeneratorArgs {
+    name: String,
+    seeds: Vec<u32>,
+    expand: Option<u64>,
+    jump: Option<Jump>,
+}
+
+#[derive(Debug)]
+struct GenerateOptions {
+    count: u64,
+    format: OutputFormat,
+    below: Option<u32>,
+    state: Option<StateFormat>,
+}
+
+#[derive(Debug)]
+struct StreamOptions {
+    bytes: Option<u64>,
+    word_bytes: usize,
+    endian: Endian,
+    interleave: usize,
+    stride: Option<Jump>,
+}
+
+#[derive(Debug)]
+struct MwcSearchOptions {
+    word_bits: u32,
+    lag: u32,
+    candidates: usize,
+    count: usize,
+}
+
+/// Parse a 32-bit unsigned value, in decimal or in hex with a `0x` prefix.
+fn parse_u32(s: &str) -> Result<u32, String> {
+    let result = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => s.parse::<u32>(),
+    };
+    result.map_err(|_| format!("invalid 32-bit value '{}'", s))
+}
+
+fn parse_u64(s: &str) -> Result<u64, String> {
+    let result = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => s.parse::<u64>(),
+    };
+    result.map_err(|_| format!("invalid 64-bit value '{}'", s))
+}
+
+/// Parse a jump-ahead count, in decimal or in hex with a `0x` prefix.
+fn parse_jump(s: &str, what: &str) -> Result<Jump, String> {
+    if let Ok(n) = s.parse::<i128>() {
+        return Ok(Jump::Signed(n));
+    }
+    let big = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => BigUint::from_str_radix(hex, 16),
+        None => BigUint::from_str_radix(s, 10),
+    };
+    big.map(Jump::Big)
+        .ok_or_else(|| format!("invalid {} '{}'", what, s))
+}
+
+fn parse_value<T: FromStr>(value: &str, what: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid {} '{}'", what, value))
+}
+
+fn option_value<'a>(
+    args: &mut impl Iterator<Item = &'a String>,
+    option: &str,
+) -> Result<&'a String, String> {
+    args.next()
+        .ok_or_else(|| format!("option '{}' requires a value", option))
+}
+
+type ArgIter<'a> = std::slice::Iter<'a, String>;
+
+/// Parse the generator name, seeds and common options.
+///
+/// Command-specific options are passed to `parse_option`, which returns `Ok(false)` for an option
+/// it doesn't know.
+fn parse_args<'a, F>(args: &'a [String], mut parse_option: F) -> Result<GeneratorArgs, String>
+where
+    F: FnMut(&str, &mut ArgIter<'a>) -> Result<bool, String>,
+{
+    let mut gen_args = GeneratorArgs {
+        name: String::new(),
+        seeds: Vec::new(),
+        expand: None,
+        jump: None,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-x" | "--expand" => {
+                let value = option_value(&mut args, arg)?;
+                gen_args.expand = Some(parse_u64(value)?);
+            }
+            "-j" | "--jump" => {
+                let value = option_value(&mut args, arg)?;
+                gen_args.jump = Some(parse_jump(value, "jump-ahead count")?);
+            }
+            _ if arg.starts_with('-') => {
+                if !parse_option(arg, &mut args)? {
+                    return Err(format!("unknown option '{}'", arg));
+                }
+            }
+            _ if gen_args.name.is_empty() => gen_args.name = arg.clone(),
+            _ => gen_args.seeds.push(parse_u32(arg)?),
+        }
+    }
+    if gen_args.name.is_empty() {
+        return Err("no generator given".to_string());
+    }
+    Ok(gen_args)
+}
+
+fn parse_generate_args(args: &[String]) -> Result<(GeneratorArgs, GenerateOptions), String> {
+    let mut opts = GenerateOptions {
+        count: 4,
+        format: OutputFormat::Dec,
+        below: None,
+        state: None,
+    };
+    let gen_args = parse_args(args, |arg, args| {
+        match arg {
+            "-n" | "--count" => opts.count = parse_value(option_value(args, arg)?, "count")?,
+            "-f" | "--format" => {
+                opts.format = match option_value(args, arg)?.as_str() {
+                    "dec" => OutputFormat::Dec,
+                    "hex" => OutputFormat::Hex,
+                    "float" => OutputFormat::Float,
+                    "float53" => OutputFormat::Float53,
+                    other => return Err(format!("unknown output format '{}'", other)),
+                };
+            }
+            "-B" | "--below" => match parse_u32(option_value(args, arg)?)? {
+                0 => return Err("bound must be non-zero".to_string()),
+                n => opts.below = Some(n),
+            },
+            "-s" | "--state" => {
+                opts.state = Some(match option_value(args, arg)?.as_str() {
+                    "debug" => StateFormat::Debug,
+                    "json" => StateFormat::Json,
+                    other => return Err(format!("unknown state format '{}'", other)),
+                });
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+    if opts.below.is_some() && matches!(opts.format, OutputFormat::Float | OutputFormat::Float53) {
+        return Err("--below gives integers, so can't be used with a float format".to_string());
+    }
+    Ok((gen_args, opts))
+}
+
+fn parse_stream_args(args: &[String]) -> Result<(GeneratorArgs, StreamOptions), String> {
+    let mut opts = StreamOptions {
+        bytes: None,
+        word_bytes: 4,
+        endian: Endian::Little,
+        interleave: 1,
+        stride: None,
+    };
+    let gen_args = parse_args(args, |arg, args| {
+        match arg {
+            "-b" | "--bytes" => {
+                opts.bytes = Some(parse_value(option_value(args, arg)?, "byte count")?);
+            }
+            "-w" | "--word" => {
+                opts.word_bytes = match option_value(args, arg)?.as_str() {
+                    "8" => 1,
+                    "16" => 2,
+                    "32" => 4,
+                    "64" => 8,
+                    other => return Err(format!("unsupported word size '{}'", other)),
+                };
+            }
+            "-e" | "--endian" => {
+                opts.endian = match option_value(args, arg)?.as_str() {
+                    "little" => Endian::Little,
+                    "big" => Endian::Big,
+                    other => return Err(format!("unknown byte order '{}'", other)),
+                };
+            }
+            "-i" | "--interleave" => {
+                opts.interleave = parse_value(option_value(args, arg)?, "substream count")?;
+                if opts.interleave == 0 {
+                    return Err("substream count must be at least 1".to_string());
+                }
+            }
+            "-t" | "--stride" => {
+                opts.stride = Some(parse_jump(option_value(args, arg)?, "stride")?);
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+    if opts.interleave > 1 && opts.stride.is_none() {
+        return Err("interleaving substreams needs a --stride".to_string());
+    }
+    Ok((gen_args, opts))
+}
+
+fn parse_mwc_search_args(args: &[String]) -> Result<MwcSearchOptions, String> {
+    let mut opts = MwcSearchOptions {
+        word_bits: 0,
+        lag: 1,
+        candidates: 100,
+        count: 10,
+    };
+    let mut word_bits = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-r" | "--lag" => opts.lag = parse_value(option_value(&mut args, arg)?, "lag")?,
+            "-c" | "--candidates" => {
+                opts.candidates = parse_value(option_value(&mut args, arg)?, "candidate count")?;
+            }
+            "-n" | "--count" => opts.count = parse_value(option_value(&mut args, arg)?, "count")?,
+            _ if arg.starts_with('-') => return Err(format!("unknown option '{}'", arg)),
+            _ if word_bits.is_none() => word_bits = Some(parse_value(arg, "word size")?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    opts.word_bits = word_bits.ok_or_else(|| "no word size given".to_string())?;
+    if opts.word_bits < 2
+        || opts.lag == 0
+        || opts.word_bits.checked_mul(opts.lag).is_none_or(|b| b > 60)
+    {
+        return Err(format!(
+            "unsupported word size {} with lag {}: need at least 2 bits, and at most 60 bits of \
+             state",
+            opts.word_bits, opts.lag
+        ));
+    }
+    Ok(opts)
+}
+
+/// Search for MWC multipliers, and print the best by spectral score.
+fn mwc_search(opts: &MwcSearchOptions) -> Result<(), String> {
+    let mut candidates: Vec<_> = ssrand::mwcsearch::MwcSearch::new(opts.word_bits, opts.lag)
+        .take(opts.candidates)
+        .collect();
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    for candidate in candidates.iter().take(opts.count) {
+        writeln!(
+            out,
+            "{} 0x{:X} {} {:.6}",
+            candidate.multiplier, candidate.modulus, candidate.period, candidate.score
         )
-    );
-    println!(
-        "wrapping_geom_series {}",
-        ssrand::math::wrapping_geom_series(12345_u32, 12345_u32)
-    );
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(())
 }
 
-fn main() {
-    test_new_and_next_u32();
+/// Convert the derived `Debug` representation of a generator to JSON.
+///
+/// The generators are plain structs of unsigned integers and other generators, so their `Debug`
+/// output has the form `Name { field: value, ... }`. Each struct becomes a JSON object, with the
+/// struct name in a `"type"` member.
+fn debug_to_json(debug: &str) -> String {
+    let mut json = String::new();
+    let mut tokens = debug
+        .split(|c: char| c.is_whitespace() || c == ',' || c == ':')
+        .filter(|token| !token.is_empty())
+        .peekable();
+    let mut need_comma = false;
+    while let Some(token) = tokens.next() {
+        match token {
+            "}" => {
+                json.push('}');
+                need_comma = true;
+            }
+            _ if token.starts_with(|c: char| c.is_ascii_digit()) => {
+                json.push_str(token);
+                need_comma = true;
+            }
+            _ if tokens.peek() == Some(&"{") => {
+                // Start of a struct: `Name {`.
+                tokens.next();
+                json.push_str(&format!("{{\"type\":\"{}\"", token));
+                need_comma = true;
+            }
+            _ => {
+                // A field name.
+                if need_comma {
+                    json.push(',');
+                }
+                json.push_str(&format!("\"{}\":", token));
+                need_comma = false;
+            }
+        }
+    }
+    json
+}
+
+fn format_state<R: Debug>(rng: &R, state: StateFormat) -> String {
+    match state {
+        StateFormat::Debug => format!("{:?}", rng),
+        StateFormat::Json => debug_to_json(&format!("{:?}", rng)),
+    }
+}
 
-    println!("");
-    test_maths();
+/// A command that can run on any of the generators.
+trait Command {
+    fn run<R>(&self, rng: R) -> io::Result<()>
+    where
+        R: RngCore + RngJumpAhead + Clone + Debug;
+}
+
+impl Command for GenerateOptions {
+    fn run<R>(&self, mut rng: R) -> io::Result<()>
+    where
+        R: RngCore + RngJumpAhead + Clone + Debug,
+    {
+        let stdout = io::stdout();
+        let mut out = stdout.lock();
+        for _ in 0..self.count {
+            match (self.below, self.format) {
+                (Some(n), OutputFormat::Hex) => write!(out, "0x{:08X}", rng.next_below(n))?,
+                (Some(n), _) => write!(out, "{}", rng.next_below(n))?,
+                (None, OutputFormat::Dec) => write!(out, "{}", rng.next_u32())?,
+                (None, OutputFormat::Hex) => write!(out, "0x{:08X}", rng.next_u32())?,
+                // Same conversion as L'Ecuyer's lfsr113.c: a 32-bit value times 2^-32.
+                (None, OutputFormat::Float) => write!(out, "{:.17}", rng.next_f64_32())?,
+                (None, OutputFormat::Float53) => write!(out, "{:.17}", rng.next_f64())?,
+            }
+            if let Some(state) = self.state {
+                write!(out, " {}", format_state(&rng, state))?;
+            }
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+}
+
+impl StreamOptions {
+    /// Number of words taken from each substream per buffer fill.
+    const CHUNK_WORDS: usize = 4096;
+
+    /// Fill `buffer` with the next part of the output stream.
+    ///
+    /// Each substream fills `chunk` using `fill_bytes()`, which gives the little-endian byte
+    /// stream of its 32-bit outputs. Its words are then copied into every `interleave`th word of
+    /// `buffer`, byte-swapped for big-endian output.
+    fn fill<R: RngCore>(&self, substreams: &mut [R], chunk: &mut [u8], buffer: &mut [u8]) {
+        let word_bytes = self.word_bytes;
+        for (i, rng) in substreams.iter_mut().enumerate() {
+            rng.fill_bytes(chunk);
+            for (j, word) in chunk.chunks_exact(word_bytes).enumerate() {
+                let start = (j * self.interleave + i) * word_bytes;
+                let dest = &mut buffer[start..start + word_bytes];
+                dest.copy_from_slice(word);
+                if self.endian == Endian::Big {
+                    dest.reverse();
+                }
+            }
+        }
+    }
+}
+
+impl Command for StreamOptions {
+    fn run<R>(&self, rng: R) -> io::Result<()>
+    where
+        R: RngCore + RngJumpAhead + Clone + Debug,
+    {
+        let mut substreams = Vec::with_capacity(self.interleave);
+        let mut substream = rng;
+        for _ in 0..self.interleave {
+            substreams.push(substream.clone());
+            if let Some(stride) = self.stride {
+                stride.apply(&mut substream);
+            }
+        }
+
+        let mut chunk = vec![0_u8; Self::CHUNK_WORDS * self.word_bytes];
+        let mut buffer = vec![0_u8; chunk.len() * self.interleave];
+        let stdout = io::stdout();
+        let mut out = stdout.lock();
+        let mut remaining = self.bytes;
+        while remaining != Some(0) {
+            self.fill(&mut substreams, &mut chunk, &mut buffer);
+            let len = match remaining {
+                Some(r) if r < buffer.len() as u64 => r as usize,
+                _ => buffer.len(),
+            };
+            match out.write_all(&buffer[..len]) {
+                // The reader has seen enough, eg PractRand reached its length limit.
+                Err(e) if e.kind() == io::ErrorKind::BrokenPipe => return Ok(()),
+                result => result?,
+            }
+            remaining = remaining.map(|r| r - len as u64);
+        }
+        out.flush()
+    }
+}
+
+/// Run a command on the generator selected by `gen_args`, after any jump-ahead.
+fn dispatch(gen_args: &GeneratorArgs, command: &impl Command) -> Result<(), String> {
+    let rng = match gen_args.expand {
+        Some(_) if !gen_args.seeds.is_empty() => {
+            return Err("seeds can't be given with --expand".to_string());
+        }
+        Some(seed) => AnyRng::from_seed_sequence(&gen_args.name, &mut SeedSequence::from_u64(seed)),
+        None => AnyRng::new(&gen_args.name, &gen_args.seeds),
+    };
+    let mut rng = rng.map_err(|e| match e {
+        ParseRngError::UnknownName => format!("unknown generator '{}'", gen_args.name),
+        ParseRngError::SeedCount { expected, given } => format!(
+            "generator {} needs {} seed(s), but {} given",
+            gen_args.name, expected, given
+        ),
+    })?;
+    if let Some(jump) = gen_args.jump {
+        jump.apply(&mut rng);
+    }
+    command.run(rng).map_err(|e| e.to_string())
+}
+
+/// List the generators, with their number of seeds and period.
+fn list() -> Result<(), String> {
+    for info in GENERATORS.iter() {
+        println!(
+            "{:<8} {}  {} (2^{:.1})",
+            info.name,
+            info.seed_count,
+            info.period,
+            (info.period as f64).log2()
+        );
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("generate") => {
+            parse_generate_args(&args[1..]).and_then(|(gen_args, opts)| dispatch(&gen_args, &opts))
+        }
+        Some("stream") => {
+            parse_stream_args(&args[1..]).and_then(|(gen_args, opts)| dispatch(&gen_args, &opts))
+        }
+        Some("list") => list(),
+        Some("mwc-search") => parse_mwc_search_args(&args[1..]).and_then(|opts| mwc_search(&opts)),
+        Some("help") | Some("-h") | Some("--help") => {
+            print!("{}", USAGE);
+            Ok(())
+        }
+        Some(other) => Err(format!("unknown command '{}'\n\n{}", other, USAGE)),
+        None => Err(format!("no command given\n\n{}", USAGE)),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("ssrand: {}", message);
+            ExitCode::from(2)
+        }
+    }
 }
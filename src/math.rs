@@ -23,13 +23,24 @@ where
 /*       Unsigned Integer types       */
 /**************************************/
 pub trait UIntTypes:
-    PrimInt + Unsigned + ConstOne + ConstZero + WrappingAdd + WrappingSub + Copy
+    PrimInt + Unsigned + ConstOne + ConstZero + WrappingAdd + WrappingSub + WrappingMul + Copy
 {
     /// Multiply unsigned `a` and `b`, modulo `m`
     ///
     /// It can be specialised for each integer type. See the comments on
     /// the generic mul_mod() implementation below.
     fn mul_mod(a: Self, b: Self, m: Self) -> Self;
+
+    /// Raise unsigned `base` to the power `n`, modulo `m`
+    ///
+    /// The default is square-and-multiply using `mul_mod()`. It is specialised for u64 and u128,
+    /// to use Montgomery multiplication when `m` is odd.
+    fn pow_mod<N>(base: Self, n: N, m: Self) -> Self
+    where
+        N: PrimInt + Unsigned + ConstOne + ConstZero,
+    {
+        pow_mod_generic(base, n, m)
+    }
 }
 
 /// Multiply unsigned `a` and `b`, modulo `m`
@@ -53,7 +64,7 @@ pub trait UIntTypes:
 ///
 /// The result is the multiplication of `a` and `b`, modulo `m`.
 ///
-///     use ssrand::maths::mul_mod;
+///     use ssrand::math::mul_mod;
 ///     let result = mul_mod(123456789_u32, 3111222333, 0x9068FFFF);
 ///     assert_eq!(1473911797_u32, result);
 ///     let result = mul_mod(12345678901234567890_u64, 10222333444555666777, 0x29A65EACFFFFFFFF);
@@ -113,23 +124,73 @@ impl UIntTypes for u32 {
 }
 impl UIntTypes for u64 {
     /// Simple specialisation using the next larger integer type
+    ///
+    /// For a single multiplication, this is faster than setting up Montgomery multiplication.
     fn mul_mod(a: Self, b: Self, m: Self) -> Self {
         ((a as u128) * (b as u128) % (m as u128)) as u64
     }
+
+    /// Montgomery multiplication for odd `m`, otherwise the generic implementation
+    fn pow_mod<N>(base: Self, n: N, m: Self) -> Self
+    where
+        N: PrimInt + Unsigned + ConstOne + ConstZero,
+    {
+        match Montgomery::<u64>::new(m) {
+            Some(montgomery) if m > 1 => montgomery.pow_mod(base, n),
+            _ => pow_mod_generic(base, n, m),
+        }
+    }
 }
 impl UIntTypes for u128 {
-    /// Use the generic implementation
+    /// Montgomery multiplication for odd `m`, otherwise the generic implementation
     fn mul_mod(a: Self, b: Self, m: Self) -> Self {
-        mul_mod_generic::<Self>(a, b, m)
+        match Montgomery::<u128>::new(m) {
+            Some(montgomery) => montgomery.mul_mod(a, b),
+            None => mul_mod_generic::<Self>(a, b, m),
+        }
+    }
+
+    /// Montgomery multiplication for odd `m`, otherwise the generic implementation
+    fn pow_mod<N>(base: Self, n: N, m: Self) -> Self
+    where
+        N: PrimInt + Unsigned + ConstOne + ConstZero,
+    {
+        match Montgomery::<u128>::new(m) {
+            Some(montgomery) if m > 1 => montgomery.pow_mod(base, n),
+            _ => pow_mod_generic(base, n, m),
+        }
     }
 }
 impl UIntTypes for usize {
-    /// Use the generic implementation
+    /// Use the implementation for u64 or u128, whichever fits
     fn mul_mod(a: Self, b: Self, m: Self) -> Self {
-        mul_mod_generic::<Self>(a, b, m)
+        if size_of::<usize>() <= size_of::<u64>() {
+            u64::mul_mod(a as u64, b as u64, m as u64) as usize
+        } else {
+            u128::mul_mod(a as u128, b as u128, m as u128) as usize
+        }
+    }
+
+    /// Use the implementation for u64 or u128, whichever fits
+    fn pow_mod<N>(base: Self, n: N, m: Self) -> Self
+    where
+        N: PrimInt + Unsigned + ConstOne + ConstZero,
+    {
+        if size_of::<usize>() <= size_of::<u64>() {
+            u64::pow_mod(base as u64, n, m as u64) as usize
+        } else {
+            u128::pow_mod(base as u128, n, m as u128) as usize
+        }
     }
 }
 
+/// Multiply unsigned `a` and `b`, modulo `m`
+///
+/// This uses [`UIntTypes::mul_mod()`], specialised for each type.
+///
+/// # Panics
+///
+/// Panics if `m` is zero. See [`checked_mul_mod()`].
 pub fn mul_mod<T>(a: T, b: T, m: T) -> T
 where
     T: UIntTypes,
@@ -137,6 +198,23 @@ where
     T::mul_mod(a, b, m)
 }
 
+/// Multiply unsigned `a` and `b`, modulo `m`, or `None` if `m` is zero
+///
+///     use ssrand::math::checked_mul_mod;
+///     assert_eq!(checked_mul_mod(123456789_u32, 3111222333, 0x9068FFFF), Some(1473911797));
+///     assert_eq!(checked_mul_mod(123456789_u32, 3111222333, 0), None);
+///
+pub fn checked_mul_mod<T>(a: T, b: T, m: T) -> Option<T>
+where
+    T: UIntTypes,
+{
+    if m == T::ZERO {
+        None
+    } else {
+        Some(T::mul_mod(a, b, m))
+    }
+}
+
 /// Primitive integer types
 ///
 /// Mappings to associated signed and unsigned types with the same bit width.
@@ -170,9 +248,9 @@ where
     if a < T::ZERO {
         // Negative input. Negate it.
         let result: Option<T::UnsignedType> = NumCast::from(a.wrapping_neg());
-        if result.is_some() {
+        if let Some(result) = result {
             // The vast majority of values.
-            result.unwrap()
+            result
         } else {
             // The exceptional case: in two's complement form, the lowest
             // negative number's negation doesn't fit into the signed type.
@@ -302,15 +380,20 @@ where
 ///
 /// # Arguments
 ///
-/// `a` can be any primitive integer, signed or unsigned.
+/// `a` can be any primitive integer, signed or unsigned, or a [`BigUint`].
 /// `m` can be any unsigned primitive integer.
 ///
 /// # Return
 ///
 /// The result is the same unsigned type as that of parameter `m`.
-/// The result is in the range [0..m] even when `a` is negative.
+/// The result is in the range [0..m) even when `a` is negative.
+///
+/// # Panics
+///
+/// Panics if `m` is zero. For any non-zero `m`, every combination of the types of `a` and `m`
+/// is supported, and it doesn't panic. See [`checked_modulo()`].
 ///
-///     use ssrand::maths::modulo;
+///     use ssrand::math::modulo;
 ///     let result = modulo(12345_u32, 7_u32);
 ///     assert_eq!(result, 4_u32);
 ///     let result = modulo(-12345_i32, 7_u32);
@@ -318,38 +401,26 @@ where
 ///
 pub fn modulo<A, M>(a: A, m: M) -> M
 where
-    A: IntTypes,
+    A: JumpCount,
     M: PrimInt + Unsigned + ConstZero + Copy + NumCast,
 {
-    if a >= A::ZERO {
-        // Positive input.
-        let a_opt: Option<M> = NumCast::from(a);
-        if a_opt.is_some() {
-            // a fits into type M. Easy.
-            a_opt.unwrap() % m
-        } else {
-            // a doesn't fit into type M. m should fit into type A.
-            let m_opt: Option<A> = NumCast::from(m);
-            let result_a = a % m_opt.unwrap();
-            let result_m: Option<M> = NumCast::from(result_a);
-            result_m.unwrap()
-        }
-    } else {
-        // Negative input.
-        let a_abs = abs_as_unsigned(a);
-        let a_abs_opt: Option<M> = NumCast::from(a_abs);
-        if a_abs_opt.is_some() {
-            // a_abs fits into type M.
-            m - (a_abs_opt.unwrap() % m)
-        } else {
-            // a_abs doesn't fit into type M. m should fit into the corresponding unsigned type of A.
-            let m_opt: Option<A::UnsignedType> = NumCast::from(m);
-            let m_s = m_opt.unwrap();
-            let result_a = m_s - (a_abs % m_s);
-            let result_m: Option<M> = NumCast::from(result_a);
-            result_m.unwrap()
-        }
-    }
+    checked_modulo(a, m).expect("modulo by zero")
+}
+
+/// Calculate `a` modulo `m`, or `None` if `m` is zero
+///
+/// Otherwise the same as [`modulo()`].
+///
+///     use ssrand::math::checked_modulo;
+///     assert_eq!(checked_modulo(-12345_i32, 7_u32), Some(3));
+///     assert_eq!(checked_modulo(-12345_i32, 0_u32), None);
+///
+pub fn checked_modulo<A, M>(a: A, m: M) -> Option<M>
+where
+    A: JumpCount,
+    M: PrimInt + Unsigned + ConstZero + Copy + NumCast,
+{
+    a.checked_modulo(m)
 }
 
 /// Exponentiation with wrapping
@@ -357,7 +428,7 @@ where
 /// Calculation of `base` to the power of an unsigned integer `n`, with the
 /// natural modulo of the unsigned integer type T (ie, with wrapping).
 ///
-///     use ssrand::maths::wrapping_pow;
+///     use ssrand::math::wrapping_pow;
 ///     let result = wrapping_pow(12345_u32, 1500000_u32);
 ///     assert_eq!(result, 2764689665_u32);
 ///
@@ -388,14 +459,14 @@ where
 /// Calculation of `base` to the power of an unsigned integer `n`,
 /// modulo a value `m`.
 ///
-///     use ssrand::maths::pow_mod;
-///     let result = pow_mod(12345_u32, 1500000_u32, 1211400191_u32);
+/// This is a generic implementation, by square-and-multiply using `mul_mod()`. See
+/// `UIntTypes::pow_mod()` which can be specialised for each type.
+///
+///     use ssrand::math::pow_mod_generic;
+///     let result = pow_mod_generic(12345_u32, 1500000_u32, 1211400191_u32);
 ///     assert_eq!(result, 348133782_u32);
-///     let result = pow_mod(0xDC28D76FFD9338E9D868AF566191DE10_u128,
-///                           0x732E73C316878E244FDFDE4EE623CDCC_u128,
-///                           0xEC327D45470669CC56B547B6FE6888A2_u128);
-///     assert_eq!(result, 0x6AA4E49D8B90A5467A9655090EDD7940_u128);
-pub fn pow_mod<T, N>(base: T, n: N, m: T) -> T
+///
+pub fn pow_mod_generic<T, N>(base: T, n: N, m: T) -> T
 where
     T: UIntTypes,
     N: PrimInt + Unsigned + ConstOne + ConstZero + BitAnd,
@@ -417,6 +488,51 @@ where
     result
 }
 
+/// Modular exponentiation
+///
+/// Calculation of `base` to the power of an unsigned integer `n`,
+/// modulo a value `m`. For u64 and u128 with odd `m`, this uses Montgomery multiplication.
+///
+/// # Panics
+///
+/// Panics if `m` is zero. See [`checked_pow_mod()`].
+///
+///     use ssrand::math::pow_mod;
+///     let result = pow_mod(12345_u32, 1500000_u32, 1211400191_u32);
+///     assert_eq!(result, 348133782_u32);
+///     let result = pow_mod(0xDC28D76FFD9338E9D868AF566191DE10_u128,
+///                           0x732E73C316878E244FDFDE4EE623CDCC_u128,
+///                           0xEC327D45470669CC56B547B6FE6888A2_u128);
+///     assert_eq!(result, 0x6AA4E49D8B90A5467A9655090EDD7940_u128);
+pub fn pow_mod<T, N>(base: T, n: N, m: T) -> T
+where
+    T: UIntTypes,
+    N: PrimInt + Unsigned + ConstOne + ConstZero + BitAnd,
+{
+    assert!(m != T::ZERO, "modulo by zero");
+    T::pow_mod(base, n, m)
+}
+
+/// Modular exponentiation, or `None` if `m` is zero
+///
+/// Otherwise the same as [`pow_mod()`], which panics on division by zero.
+///
+///     use ssrand::math::checked_pow_mod;
+///     assert_eq!(checked_pow_mod(12345_u32, 1500000_u32, 1211400191_u32), Some(348133782));
+///     assert_eq!(checked_pow_mod(12345_u32, 1500000_u32, 0), None);
+///
+pub fn checked_pow_mod<T, N>(base: T, n: N, m: T) -> Option<T>
+where
+    T: UIntTypes,
+    N: PrimInt + Unsigned + ConstOne + ConstZero + BitAnd,
+{
+    if m == T::ZERO {
+        None
+    } else {
+        Some(pow_mod(base, n, m))
+    }
+}
+
 /// Calculate geometric series
 ///
 /// That is, calculate the geometric series:
@@ -437,7 +553,7 @@ where
 /// This implementation is by a loop, not recursion, with time order
 /// `O(log n)` and stack depth `O(1)`.
 ///
-///     use ssrand::maths::wrapping_geom_series;
+///     use ssrand::math::wrapping_geom_series;
 ///     let result = wrapping_geom_series(12345_u32, 1500000_u32);
 ///     assert_eq!(result, 57634016_u32);
 ///
@@ -476,3 +592,1164 @@ where
     result = result.wrapping_add(&mult);
     result
 }
+
+/// Iterator over the distinct prime factors of an unsigned integer
+///
+/// Created by [`prime_factors()`].
+#[derive(Clone, Debug)]
+pub struct PrimeFactors<T> {
+    n: T,
+    divisor: T,
+}
+
+/// Distinct prime factors of `n`, in increasing order
+///
+/// The factors are found by trial division, so this is slow if `n` has two or more large prime
+/// factors (eg above 2^50). A remaining factor that is prime is found quickly by `is_prime()`.
+/// It is fine for the periods of the generators in this crate.
+///
+///     use ssrand::math::prime_factors;
+///     let mut factors = prime_factors(0xFFFFFFFF_u32);
+///     assert_eq!(factors.next(), Some(3));
+///     assert_eq!(factors.next(), Some(5));
+///     assert_eq!(factors.next(), Some(17));
+///     assert_eq!(factors.next(), Some(257));
+///     assert_eq!(factors.next(), Some(65537));
+///     assert_eq!(factors.next(), None);
+///
+pub fn prime_factors<T>(n: T) -> PrimeFactors<T>
+where
+    T: UIntTypes,
+{
+    PrimeFactors {
+        n,
+        divisor: T::ONE + T::ONE,
+    }
+}
+
+impl<T> Iterator for PrimeFactors<T>
+where
+    T: UIntTypes,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let two = T::ONE + T::ONE;
+        if self.n <= T::ONE {
+            return None;
+        }
+        if is_prime(self.n) {
+            // No need to search for a large prime factor.
+            let result = self.n;
+            self.n = T::ONE;
+            return Some(result);
+        }
+        while self.divisor <= self.n / self.divisor {
+            let divisor = self.divisor;
+            self.divisor = if divisor == two {
+                divisor + T::ONE
+            } else {
+                divisor + two
+            };
+            if self.n % divisor == T::ZERO {
+                while self.n % divisor == T::ZERO {
+                    self.n = self.n / divisor;
+                }
+                return Some(divisor);
+            }
+        }
+        // What remains has no factor up to its square root, so is prime.
+        let result = self.n;
+        self.n = T::ONE;
+        Some(result)
+    }
+}
+
+/**************************************/
+/*            Jump counts             */
+/**************************************/
+
+/// Jump counts, for [`RngJumpAhead::jumpahead()`](crate::RngJumpAhead::jumpahead)
+///
+/// Implemented for all the primitive integer types, signed and unsigned, and for [`BigUint`],
+/// for counts beyond the range of `u128`. A generator only needs the count modulo its cycle
+/// length, so that is the only operation.
+pub trait JumpCount: Copy {
+    /// Calculate `self` modulo `m`, in the range [0..m), or `None` if `m` is zero
+    fn checked_modulo<M>(self, m: M) -> Option<M>
+    where
+        M: PrimInt + Unsigned + ConstZero + Copy + NumCast;
+}
+
+impl<T> JumpCount for T
+where
+    T: IntTypes,
+{
+    fn checked_modulo<M>(self, m: M) -> Option<M>
+    where
+        M: PrimInt + Unsigned + ConstZero + Copy + NumCast,
+    {
+        if m == M::ZERO {
+            return None;
+        }
+        // The conversions can't fail. If `self` (or its magnitude) doesn't fit into type M, then
+        // M has the smaller range, and `m` fits into the type of `self`, and so does the remainder.
+        let a_abs = abs_as_unsigned(self);
+        let a_abs_opt: Option<M> = NumCast::from(a_abs);
+        let remainder: M = if let Some(a_abs_m) = a_abs_opt {
+            // a_abs fits into type M. Easy.
+            a_abs_m % m
+        } else {
+            let m_s: T::UnsignedType = NumCast::from(m)?;
+            NumCast::from(a_abs % m_s)?
+        };
+        if self < T::ZERO && remainder != M::ZERO {
+            Some(m - remainder)
+        } else {
+            Some(remainder)
+        }
+    }
+}
+
+/// Unsigned integer of `LIMBS` 64-bit limbs, least significant first
+///
+/// This is a minimal big integer, for jump counts beyond `u128`, such as a multiple of 2^127 as
+/// the stride between substreams, and for the [spectral test](crate::spectral) of large moduli.
+/// Eg a 256-bit count is `BigUint<4>`.
+///
+///     use ssrand::math::{modulo, BigUint};
+///     // 2^127 × 3
+///     let n = BigUint::<4>::from_u128(1 << 127).checked_mul_u64(3).unwrap();
+///     assert_eq!(n.limbs(), [0, 1 << 63, 1, 0]);
+///     assert_eq!(modulo(n, 1_000_000_007_u32), 919448419);
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BigUint<const LIMBS: usize> {
+    limbs: [u64; LIMBS],
+}
+
+impl<const LIMBS: usize> BigUint<LIMBS> {
+    pub const ZERO: Self = BigUint { limbs: [0; LIMBS] };
+
+    /// From limbs, least significant first
+    pub const fn from_limbs(limbs: [u64; LIMBS]) -> Self {
+        BigUint { limbs }
+    }
+
+    /// The limbs, least significant first
+    pub const fn limbs(&self) -> [u64; LIMBS] {
+        self.limbs
+    }
+
+    /// From a `u128`, truncated if `LIMBS` is less than 2
+    pub const fn from_u128(n: u128) -> Self {
+        let mut limbs = [0; LIMBS];
+        if LIMBS > 0 {
+            limbs[0] = n as u64;
+        }
+        if LIMBS > 1 {
+            limbs[1] = (n >> 64) as u64;
+        }
+        BigUint { limbs }
+    }
+
+    /// Number of significant bits, ie the position of the highest set bit plus one
+    pub fn bits(&self) -> usize {
+        match self.limbs.iter().rposition(|&limb| limb != 0) {
+            Some(i) => i * 64 + 64 - self.limbs[i].leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Bit `i`, counting from the least significant bit 0
+    pub fn bit(&self, i: usize) -> bool {
+        i < LIMBS * 64 && (self.limbs[i / 64] >> (i % 64)) & 1 != 0
+    }
+
+    /// Multiply by `rhs`, or `None` on overflow
+    pub fn checked_mul_u64(self, rhs: u64) -> Option<Self> {
+        let mut result = self;
+        let mut carry = 0_u64;
+        for limb in result.limbs.iter_mut() {
+            let product = (*limb as u128) * (rhs as u128) + carry as u128;
+            *limb = product as u64;
+            carry = (product >> 64) as u64;
+        }
+        if carry == 0 {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Add `rhs`, or `None` on overflow
+    pub fn checked_add_u64(self, rhs: u64) -> Option<Self> {
+        let mut result = self;
+        let mut carry = rhs;
+        for limb in result.limbs.iter_mut() {
+            if carry == 0 {
+                break;
+            }
+            let (sum, overflow) = limb.overflowing_add(carry);
+            *limb = sum;
+            carry = overflow as u64;
+        }
+        if carry == 0 {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Shift left by `shift` bits, or `None` if any set bits would be lost
+    pub fn checked_shl(self, shift: usize) -> Option<Self> {
+        if self.bits() + shift > LIMBS * 64 {
+            return if self == Self::ZERO { Some(self) } else { None };
+        }
+        let (limb_shift, bit_shift) = (shift / 64, shift % 64);
+        let mut result = Self::ZERO;
+        for i in limb_shift..LIMBS {
+            let src = i - limb_shift;
+            result.limbs[i] = self.limbs[src] << bit_shift;
+            if bit_shift > 0 && src > 0 {
+                result.limbs[i] |= self.limbs[src - 1] >> (64 - bit_shift);
+            }
+        }
+        Some(result)
+    }
+
+    /// Add `rhs`, or `None` on overflow
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let mut result = self;
+        let mut carry = false;
+        for (limb, &rhs_limb) in result.limbs.iter_mut().zip(rhs.limbs.iter()) {
+            let (sum, overflow1) = limb.overflowing_add(rhs_limb);
+            let (sum, overflow2) = sum.overflowing_add(carry as u64);
+            *limb = sum;
+            carry = overflow1 || overflow2;
+        }
+        if carry {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Subtract `rhs`, or `None` if `rhs` is greater than `self`
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (result, borrow) = self.overflowing_sub(rhs);
+        if borrow {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Subtract `rhs` modulo 2^(64·LIMBS), and whether it borrowed
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let mut result = self;
+        let mut borrow = false;
+        for (limb, &rhs_limb) in result.limbs.iter_mut().zip(rhs.limbs.iter()) {
+            let (difference, overflow1) = limb.overflowing_sub(rhs_limb);
+            let (difference, overflow2) = difference.overflowing_sub(borrow as u64);
+            *limb = difference;
+            borrow = overflow1 || overflow2;
+        }
+        (result, borrow)
+    }
+
+    /// Multiply by `rhs`, or `None` on overflow
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let mut result = Self::ZERO;
+        for (i, &limb) in self
+            .limbs
+            .iter()
+            .enumerate()
+            .filter(|&(_, &limb)| limb != 0)
+        {
+            let mut carry = 0_u64;
+            for (j, &rhs_limb) in rhs.limbs.iter().enumerate() {
+                // At most (2^64 - 1)² + 2·(2^64 - 1), which fits.
+                let product = (limb as u128) * (rhs_limb as u128) + carry as u128;
+                match result.limbs.get_mut(i + j) {
+                    Some(result_limb) => {
+                        let sum = product + *result_limb as u128;
+                        *result_limb = sum as u64;
+                        carry = (sum >> 64) as u64;
+                    }
+                    None if product != 0 => return None,
+                    None => carry = 0,
+                }
+            }
+            if carry != 0 {
+                return None;
+            }
+        }
+        Some(result)
+    }
+
+    /// Quotient and remainder of division by `rhs`, or `None` if `rhs` is zero
+    ///
+    /// This is long division, one bit at a time.
+    pub fn checked_div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+        if rhs == Self::ZERO {
+            return None;
+        }
+        let mut quotient = Self::ZERO;
+        let mut remainder = Self::ZERO;
+        for i in (0..self.bits()).rev() {
+            // Shift the next bit into the remainder. If a bit is shifted out, the remainder is
+            // certainly at least `rhs`, and the subtraction below wraps to the right value.
+            let carry = remainder.limbs.last().is_some_and(|&limb| limb >> 63 != 0);
+            let mut shifted_in = self.bit(i) as u64;
+            for limb in remainder.limbs.iter_mut() {
+                let shifted_out = *limb >> 63;
+                *limb = (*limb << 1) | shifted_in;
+                shifted_in = shifted_out;
+            }
+            if carry || remainder >= rhs {
+                remainder = remainder.overflowing_sub(rhs).0;
+                quotient.limbs[i / 64] |= 1 << (i % 64);
+            }
+        }
+        Some((quotient, remainder))
+    }
+
+    /// Integer square root, ie the largest `r` such that `r·r <= self`
+    pub fn isqrt(self) -> Self {
+        if self.bits() <= 1 {
+            return self;
+        }
+        // Newton's iteration, from a first estimate that is at least the root, as for isqrt().
+        let mut x = Self::from_u128(1)
+            .checked_shl(self.bits().div_ceil(2))
+            .unwrap_or(self);
+        loop {
+            let (quotient, _) = self.checked_div_rem(x).unwrap_or((Self::ZERO, Self::ZERO));
+            // x + self/x can be one bit wider than x, so halve each term first.
+            let y = x.halved().checked_add(quotient.halved());
+            let y = y.and_then(|y| y.checked_add_u64((x.bit(0) && quotient.bit(0)) as u64));
+            match y {
+                Some(y) if y < x => x = y,
+                _ => return x,
+            }
+        }
+    }
+
+    /// Shift right by one bit
+    fn halved(self) -> Self {
+        let mut result = self;
+        let mut shifted_in = 0;
+        for limb in result.limbs.iter_mut().rev() {
+            let shifted_out = *limb & 1;
+            *limb = (*limb >> 1) | (shifted_in << 63);
+            shifted_in = shifted_out;
+        }
+        result
+    }
+
+    /// Parse from a string of digits in the given radix, from 2 to 36
+    ///
+    /// Returns `None` if the string is empty, has an invalid digit, or the value overflows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not from 2 to 36.
+    pub fn from_str_radix(s: &str, radix: u32) -> Option<Self> {
+        assert!((2..=36).contains(&radix), "radix must be from 2 to 36");
+        if s.is_empty() {
+            return None;
+        }
+        s.chars().try_fold(Self::ZERO, |result, c| {
+            result
+                .checked_mul_u64(radix as u64)?
+                .checked_add_u64(c.to_digit(radix)? as u64)
+        })
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for BigUint<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Ord for BigUint<LIMBS> {
+    /// Compared from the most significant limb.
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+
+impl<const LIMBS: usize> JumpCount for BigUint<LIMBS> {
+    /// Reduced one limb at a time, from the most significant, by Horner's rule.
+    fn checked_modulo<M>(self, m: M) -> Option<M>
+    where
+        M: PrimInt + Unsigned + ConstZero + Copy + NumCast,
+    {
+        if m == M::ZERO {
+            return None;
+        }
+        let m_128: u128 = NumCast::from(m)?;
+        let limb_radix = if m_128 > 1 << 64 {
+            1 << 64
+        } else {
+            (1 << 64) % m_128
+        };
+        let remainder = self.limbs.iter().rev().fold(0_u128, |r, &limb| {
+            add_mod(mul_mod(r, limb_radix, m_128), limb as u128 % m_128, m_128)
+        });
+        NumCast::from(remainder)
+    }
+}
+
+/// The jump count 2^`k`, for any `k`
+///
+/// This is the count used by the default
+/// [`RngJumpAhead::jumpahead_pow2()`](crate::RngJumpAhead::jumpahead_pow2), for counts far
+/// beyond even [`BigUint`].
+///
+///     use ssrand::math::{modulo, Pow2};
+///     assert_eq!(modulo(Pow2(10), 1000_u32), 24);
+///     assert_eq!(modulo(Pow2(1000), 1_000_000_007_u32), 688423210);
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Pow2(pub u32);
+
+impl JumpCount for Pow2 {
+    /// By modular exponentiation.
+    fn checked_modulo<M>(self, m: M) -> Option<M>
+    where
+        M: PrimInt + Unsigned + ConstZero + Copy + NumCast,
+    {
+        let m_128: u128 = NumCast::from(m)?;
+        NumCast::from(checked_pow_mod(2_u128, self.0, m_128)?)
+    }
+}
+
+/**************************************/
+/*             Primality              */
+/**************************************/
+
+/// Bases for the Miller–Rabin test: the first 13 primes.
+const MILLER_RABIN_BASES: [u8; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// Test whether `n` is prime, by the Miller–Rabin test
+///
+/// The first 13 primes are used as bases, which makes the test deterministic for all
+/// `n < 3,317,044,064,679,887,385,961,981` (about 2^81), which is itself the smallest composite
+/// that passes. That covers every u8 to u64 (and usize), and u128 values below that bound. Above
+/// it, the result is a strong probable-prime test to 13 bases, not a proof.
+///
+///     use ssrand::math::is_prime;
+///     assert!(is_prime(0xFFFFFFFB_u32));
+///     assert!(!is_prime(0xFFFFFFFF_u32));
+///     assert!(is_prime((1_u128 << 89) - 1));
+///
+pub fn is_prime<T>(n: T) -> bool
+where
+    T: UIntTypes,
+{
+    if n <= T::ONE {
+        return false;
+    }
+    // Trial division by the bases, which also handles small n.
+    for &base in MILLER_RABIN_BASES.iter() {
+        let base: T = NumCast::from(base).unwrap();
+        if n == base {
+            return true;
+        }
+        if n % base == T::ZERO {
+            return false;
+        }
+    }
+
+    // n - 1 = d·2^s, with d odd
+    let n_minus_1 = n - T::ONE;
+    let s = n_minus_1.trailing_zeros();
+    let d = n_minus_1 >> s as usize;
+    'bases: for &base in MILLER_RABIN_BASES.iter() {
+        let base: T = NumCast::from(base).unwrap();
+        let mut x = pow_mod(base, d, n);
+        if x == T::ONE || x == n_minus_1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n_minus_1 {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Test whether `p` is a safe prime, ie `p` and `(p - 1)/2` are both prime
+///
+///     use ssrand::math::is_safe_prime;
+///     assert!(is_safe_prime(23_u32));
+///     assert!(!is_safe_prime(13_u32));
+///
+pub fn is_safe_prime<T>(p: T) -> bool
+where
+    T: UIntTypes,
+{
+    is_prime(p) && is_prime((p - T::ONE) >> 1)
+}
+
+/// Test whether `multiplier` is valid for a multiply-with-carry generator with `half_bits` bits
+/// of state in each half
+///
+/// An MWC generator with multiplier M and base b = 2^`half_bits` has period `(M·b)/2 - 1` only if
+/// its modulus `M·b - 1` is a safe prime. Returns false if the modulus doesn't fit into type T.
+///
+///     use ssrand::math::is_mwc_multiplier;
+///     assert!(is_mwc_multiplier(36969_u32, 16));
+///     assert!(!is_mwc_multiplier(36970_u32, 16));
+///
+pub fn is_mwc_multiplier<T>(multiplier: T, half_bits: usize) -> bool
+where
+    T: UIntTypes,
+{
+    if multiplier == T::ZERO
+        || half_bits >= size_of_bits::<T>()
+        || multiplier > T::max_value() >> half_bits
+    {
+        return false;
+    }
+    is_safe_prime((multiplier << half_bits) - T::ONE)
+}
+
+/**************************************/
+/*     Inverses and logarithms        */
+/**************************************/
+
+/// Greatest common divisor of unsigned `a` and `b`
+///
+///     use ssrand::math::gcd;
+///     assert_eq!(gcd(12_u32, 18), 6);
+///     assert_eq!(gcd(0_u32, 5), 5);
+///
+pub fn gcd<T>(a: T, b: T) -> T
+where
+    T: UIntTypes,
+{
+    let mut a_work = a;
+    let mut b_work = b;
+    while b_work != T::ZERO {
+        let r = a_work % b_work;
+        a_work = b_work;
+        b_work = r;
+    }
+    a_work
+}
+
+/// Extended Euclidean algorithm, with the Bézout coefficients in wrapping arithmetic.
+///
+/// Returns `(g, x, y)` where `g = gcd(a, b)` and `a·x + b·y = g`, with `x` and `y` modulo 2^bits.
+/// The true coefficients have magnitude at most max(a, b)/2 (or are 0 or 1), so they can be
+/// recovered as signed values.
+fn extended_gcd_wrapping<T>(a: T, b: T) -> (T, T, T)
+where
+    T: UIntTypes,
+{
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (T::ONE, T::ZERO);
+    let (mut old_t, mut t) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let q = old_r / r;
+        let new_r = old_r - q * r;
+        old_r = r;
+        r = new_r;
+        let new_s = old_s.wrapping_sub(&q.wrapping_mul(&s));
+        old_s = s;
+        s = new_s;
+        let new_t = old_t.wrapping_sub(&q.wrapping_mul(&t));
+        old_t = t;
+        t = new_t;
+    }
+    (old_r, old_s, old_t)
+}
+
+/// Convert a wrapped coefficient from `extended_gcd_wrapping()` to the signed type.
+fn wrapped_to_signed<T>(x: T) -> T::SignedType
+where
+    T: UIntTypes + IntTypes,
+{
+    if x > T::max_value() >> 1 {
+        let magnitude: Option<T::SignedType> = NumCast::from(x.wrapping_neg());
+        T::SignedType::ZERO - magnitude.unwrap()
+    } else {
+        let value: Option<T::SignedType> = NumCast::from(x);
+        value.unwrap()
+    }
+}
+
+/// Extended Euclidean algorithm
+///
+/// Returns `(g, x, y)` where `g` is the greatest common divisor of `a` and `b`, and `x` and `y`
+/// are Bézout coefficients such that `a·x + b·y = g`. The coefficients are the signed type of
+/// the same bit width, which they always fit.
+///
+///     use ssrand::math::extended_gcd;
+///     assert_eq!(extended_gcd(240_u32, 46), (2, -9, 47));
+///
+pub fn extended_gcd<T>(a: T, b: T) -> (T, T::SignedType, T::SignedType)
+where
+    T: UIntTypes + IntTypes,
+{
+    let (g, x, y) = extended_gcd_wrapping(a, b);
+    (g, wrapped_to_signed(x), wrapped_to_signed(y))
+}
+
+/// Inverse of `a` modulo `m`
+///
+/// Returns `x` in the range [0..m) such that `a·x = 1` modulo `m`, or `None` if `a` and `m` are
+/// not coprime (or `m` is zero).
+///
+///     use ssrand::math::mod_inverse;
+///     assert_eq!(mod_inverse(3_u32, 7), Some(5));
+///     assert_eq!(mod_inverse(4_u32, 6), None);
+///
+pub fn mod_inverse<T>(a: T, m: T) -> Option<T>
+where
+    T: UIntTypes,
+{
+    if m == T::ZERO {
+        return None;
+    }
+    let (g, x, _) = extended_gcd_wrapping(a % m, m);
+    if g != T::ONE {
+        None
+    } else if x > T::max_value() >> 1 {
+        // Negative coefficient.
+        Some(m - T::ZERO.wrapping_sub(&x))
+    } else {
+        Some(x)
+    }
+}
+
+/// Inverse of odd `a`, with the natural modulo of the unsigned integer type T (ie, with wrapping)
+///
+/// That is, modulo 2^bits. Returns `None` if `a` is even. It is calculated by Newton's iteration:
+/// an odd number is its own inverse modulo 8, and each step doubles the number of correct bits.
+///
+/// This can reverse a multiplicative step, eg of the `Cong` generator.
+///
+///     use ssrand::math::wrapping_inverse;
+///     let inverse = wrapping_inverse(69069_u32).unwrap();
+///     assert_eq!(inverse.wrapping_mul(69069), 1);
+///     assert_eq!(wrapping_inverse(10_u32), None);
+///
+pub fn wrapping_inverse<T>(a: T) -> Option<T>
+where
+    T: PrimInt + Unsigned + WrappingMul + WrappingSub + ConstOne + ConstZero,
+{
+    if a & T::ONE == T::ZERO {
+        return None;
+    }
+    let two = T::ONE + T::ONE;
+    let mut inverse = a;
+    while a.wrapping_mul(&inverse) != T::ONE {
+        inverse = inverse.wrapping_mul(&two.wrapping_sub(&a.wrapping_mul(&inverse)));
+    }
+    Some(inverse)
+}
+
+/// `a + b` modulo `m`, for `a` and `b` less than `m`, without overflow.
+fn add_mod<T: UIntTypes>(a: T, b: T, m: T) -> T {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// `a - b` modulo `m`, for `a` and `b` less than `m`.
+fn sub_mod<T: UIntTypes>(a: T, b: T, m: T) -> T {
+    if a >= b {
+        a - b
+    } else {
+        m - (b - a)
+    }
+}
+
+/// Discrete logarithm of `h` to base `gamma` modulo `m`, where `gamma` has prime order `q`.
+///
+/// Exhaustive search for small `q`, otherwise Pollard's rho algorithm, which takes time
+/// O(sqrt(q)) and constant space.
+fn prime_order_log<T: UIntTypes>(gamma: T, h: T, m: T, q: T) -> Option<T> {
+    if h == T::ONE {
+        return Some(T::ZERO);
+    }
+    let small: T = NumCast::from(1024).unwrap_or_else(T::max_value);
+    if q <= small {
+        let mut power = gamma;
+        let mut d = T::ONE;
+        while d < q {
+            if power == h {
+                return Some(d);
+            }
+            power = mul_mod(power, gamma, m);
+            d = d + T::ONE;
+        }
+        return None;
+    }
+
+    // Walk x = gamma^a · h^b, in three partitions, until Floyd's cycle detection finds a collision.
+    let three = T::ONE + T::ONE + T::ONE;
+    let step = |(x, a, b): (T, T, T)| -> (T, T, T) {
+        let partition = x % three;
+        if partition == T::ZERO {
+            (mul_mod(x, x, m), add_mod(a, a, q), add_mod(b, b, q))
+        } else if partition == T::ONE {
+            (mul_mod(x, gamma, m), add_mod(a, T::ONE, q), b)
+        } else {
+            (mul_mod(x, h, m), a, add_mod(b, T::ONE, q))
+        }
+    };
+    let mut a_start = T::ZERO;
+    for _ in 0..32 {
+        // Try a different starting point each attempt, if the collision gives no answer.
+        a_start = add_mod(a_start, T::ONE, q);
+        let start = (mul_mod(pow_mod(gamma, a_start, m), h, m), a_start, T::ONE);
+        let mut tortoise = step(start);
+        let mut hare = step(tortoise);
+        while tortoise.0 != hare.0 {
+            tortoise = step(tortoise);
+            hare = step(step(hare));
+        }
+        // gamma^a1 · h^b1 = gamma^a2 · h^b2, so d·(b1 - b2) = a2 - a1 modulo q.
+        let b_diff = sub_mod(tortoise.2, hare.2, q);
+        if let Some(b_inverse) = mod_inverse(b_diff, q) {
+            let d = mul_mod(sub_mod(hare.1, tortoise.1, q), b_inverse, q);
+            if pow_mod(gamma, d, m) == h {
+                return Some(d);
+            }
+        }
+    }
+    None
+}
+
+/// Discrete logarithm modulo `m`
+///
+/// Returns `x` such that `base^x = target` modulo `m`, or `None` if there is no such `x`. `x` is
+/// the smallest solution, less than the multiplicative order of `base`.
+///
+/// `order` must be a multiple of the multiplicative order of `base` modulo `m`, eg the order of
+/// the multiplicative group, or a generator's cycle length. This uses the Pohlig–Hellman
+/// algorithm, which breaks the problem down by the prime factors of the order, and Pollard's rho
+/// algorithm for each prime factor. So it takes time O(sqrt(q)) for the largest prime factor q of
+/// the order, plus the time to factor the order by trial division. It is fast when the order has
+/// only small factors, but impractical for a prime order much above 2^50.
+///
+/// This can measure the distance between two states of a multiplicative generator.
+///
+/// # Panics
+///
+/// Panics if `base^order` is not 1 modulo `m`.
+///
+///     use ssrand::math::{discrete_log, pow_mod};
+///     // 3 generates the multiplicative group modulo 7, of order 6.
+///     assert_eq!(discrete_log(3_u32, 6, 7, 6), Some(3));
+///     assert_eq!(pow_mod(3_u32, 3_u32, 7), 6);
+///     // 2 has order 3 modulo 7, and doesn't generate 3.
+///     assert_eq!(discrete_log(2_u32, 3, 7, 6), None);
+///
+pub fn discrete_log<T>(base: T, target: T, m: T, order: T) -> Option<T>
+where
+    T: UIntTypes,
+{
+    if m == T::ONE {
+        // Every value is 0.
+        return Some(T::ZERO);
+    }
+    let one = T::ONE;
+    let base = base % m;
+    let target = target % m;
+    assert!(
+        pow_mod(base, order, m) == one,
+        "order is not a multiple of the order of base"
+    );
+
+    // Reduce to the exact order of base.
+    let mut n = order;
+    for q in prime_factors(order) {
+        while n % q == T::ZERO && pow_mod(base, n / q, m) == one {
+            n = n / q;
+        }
+    }
+
+    // Solve modulo each prime power q^e dividing n, one base-q digit at a time, and combine the
+    // results by the Chinese remainder theorem.
+    let mut x = T::ZERO;
+    let mut x_modulus = T::ONE;
+    for q in prime_factors(n) {
+        let mut q_e = q;
+        while (n / q_e) % q == T::ZERO {
+            q_e = q_e * q;
+        }
+        let cofactor = n / q_e;
+        // base_i has order q^e, and gamma has order q.
+        let base_i = pow_mod(base, cofactor, m);
+        let target_i = pow_mod(target, cofactor, m);
+        let gamma = pow_mod(base_i, q_e / q, m);
+        let base_i_inverse = mod_inverse(base_i, m)?;
+
+        let mut x_i = T::ZERO;
+        let mut q_k = T::ONE;
+        while q_k < q_e {
+            let remaining = mul_mod(pow_mod(base_i_inverse, x_i, m), target_i, m);
+            let h = pow_mod(remaining, q_e / q / q_k, m);
+            let digit = prime_order_log(gamma, h, m, q)?;
+            x_i = x_i + digit * q_k;
+            q_k = q_k * q;
+        }
+
+        let x_mod_q_e = x % q_e;
+        let t = mul_mod(
+            sub_mod(x_i, x_mod_q_e, q_e),
+            mod_inverse(x_modulus % q_e, q_e)?,
+            q_e,
+        );
+        x = x + x_modulus * t;
+        x_modulus = x_modulus * q_e;
+    }
+
+    if pow_mod(base, x, m) == target {
+        Some(x)
+    } else {
+        None
+    }
+}
+
+/**************************************/
+/*               Roots                */
+/**************************************/
+
+/// Integer square root, ie the largest `r` such that `r·r <= n`
+///
+///     use ssrand::math::isqrt;
+///     assert_eq!(isqrt(99_u32), 9);
+///     assert_eq!(isqrt(100_u32), 10);
+///     assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+///
+pub fn isqrt<T>(n: T) -> T
+where
+    T: UIntTypes,
+{
+    if n <= T::ONE {
+        return n;
+    }
+    // Newton's iteration, from a first estimate that is at least the root.
+    let bits = size_of_bits::<T>() - n.leading_zeros() as usize;
+    let mut x = T::ONE << bits.div_ceil(2);
+    loop {
+        let y = (x + n / x) >> 1;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// `n`th root of a non-negative `f64`
+///
+/// This is for `no_std` use, where `f64::sqrt()` and `f64::powf()` aren't available. It uses
+/// Newton's iteration, from a first estimate made by dividing the exponent by `n`.
+///
+///     use ssrand::math::root_f64;
+///     assert!((root_f64(2.0, 2) - 1.4142135623730951).abs() < 1e-15);
+///     assert!((root_f64(4294967296.0, 8) - 16.0).abs() < 1e-13);
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+pub fn root_f64(x: f64, n: u32) -> f64 {
+    assert!(n > 0, "zeroth root");
+    if x <= 0.0 || n == 1 || x.is_infinite() {
+        return x.max(0.0);
+    }
+    let exponent = ((x.to_bits() >> 52) & 0x7FF) as i64 - 1023;
+    let mut result = f64::from_bits(((exponent / n as i64 + 1023) as u64) << 52);
+    for _ in 0..100 {
+        let mut power = 1.0;
+        for _ in 1..n {
+            power *= result;
+        }
+        let next = ((n - 1) as f64 * result + x / power) / n as f64;
+        if (next - result).abs() <= 1e-15 * next {
+            return next;
+        }
+        result = next;
+    }
+    result
+}
+
+/**************************************/
+/*     Montgomery multiplication      */
+/**************************************/
+
+/// Full product of two u64 values, as (low, high) halves.
+const fn mul_wide_u64(a: u64, b: u64) -> (u64, u64) {
+    let product = (a as u128) * (b as u128);
+    (product as u64, (product >> 64) as u64)
+}
+
+/// Full product of two u128 values, as (low, high) halves.
+const fn mul_wide_u128(a: u128, b: u128) -> (u128, u128) {
+    let (a_lo, a_hi) = (a as u64 as u128, a >> 64);
+    let (b_lo, b_hi) = (b as u64 as u128, b >> 64);
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let hi_hi = a_hi * b_hi;
+    let middle = (lo_lo >> 64) + (lo_hi as u64 as u128) + (hi_lo as u64 as u128);
+    let lo = (lo_lo as u64 as u128) | (middle << 64);
+    let hi = hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (middle >> 64);
+    (lo, hi)
+}
+
+/// Montgomery multiplication modulo an odd `m`
+///
+/// A value `a` is represented in Montgomery form as `a·R mod m`, where R is 2 to the power of
+/// the bit width of `T`. Then the product of two values in Montgomery form can be reduced modulo
+/// `m` with multiplications and a shift (REDC), rather than a division. That is much faster than
+/// the generic shift-and-add `mul_mod_generic()` for u128, and than u128 division for u64.
+///
+/// Converting into and out of Montgomery form costs about one multiplication each, and `new()`
+/// a few more, so it pays off for a series of multiplications with the same modulus, such as
+/// `pow_mod()`. It is implemented for u64 and u128.
+///
+///     use ssrand::math::Montgomery;
+///     let montgomery = Montgomery::<u64>::new(0xFFFFFFFFFFFFFFC5).unwrap();
+///     assert_eq!(montgomery.pow_mod(2, 0xFFFFFFFFFFFFFFC4_u64), 1);
+///     assert!(Montgomery::<u64>::new(1 << 40).is_none());
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Montgomery<T> {
+    /// Modulus
+    m: T,
+    /// Inverse of `m` modulo R
+    m_inv: T,
+    /// R modulo `m`, ie 1 in Montgomery form
+    r1: T,
+    /// R² modulo `m`, for conversion into Montgomery form
+    r2: T,
+}
+
+macro_rules! montgomery_impl {
+    ($t:ty, $mul_wide:ident) => {
+        impl Montgomery<$t> {
+            /// Set up Montgomery multiplication modulo `m`
+            ///
+            /// Returns `None` if `m` is even.
+            pub fn new(m: $t) -> Option<Montgomery<$t>> {
+                let m_inv = wrapping_inverse(m)?;
+                let r1 = m.wrapping_neg() % m;
+                let mut result = Montgomery::<$t> {
+                    m,
+                    m_inv,
+                    r1,
+                    r2: 0,
+                };
+                // Start from 2 in Montgomery form, then square it until it is 2^(bit width) = R.
+                let mut r2 = if r1 >= m - r1 { r1 - (m - r1) } else { r1 + r1 };
+                for _ in 0..<$t>::BITS.trailing_zeros() {
+                    r2 = result.mul(r2, r2);
+                }
+                result.r2 = r2;
+                Some(result)
+            }
+
+            /// The modulus `m`
+            pub fn modulus(&self) -> $t {
+                self.m
+            }
+
+            /// Reduce `hi·R + lo` to `(hi·R + lo)·R⁻¹ mod m`. Requires `hi < m`.
+            fn redc(&self, lo: $t, hi: $t) -> $t {
+                // q·m = lo modulo R, so the low halves cancel.
+                let q = lo.wrapping_mul(self.m_inv);
+                let qm_hi = $mul_wide(q, self.m).1;
+                if hi >= qm_hi {
+                    hi - qm_hi
+                } else {
+                    hi.wrapping_sub(qm_hi).wrapping_add(self.m)
+                }
+            }
+
+            /// Multiply two values in Montgomery form
+            ///
+            /// `a` and `b` must be less than `m`.
+            pub fn mul(&self, a: $t, b: $t) -> $t {
+                let (lo, hi) = $mul_wide(a, b);
+                self.redc(lo, hi)
+            }
+
+            /// Convert `a` into Montgomery form
+            pub fn to_montgomery(&self, a: $t) -> $t {
+                self.mul(a % self.m, self.r2)
+            }
+
+            /// Convert `a` from Montgomery form
+            pub fn from_montgomery(&self, a: $t) -> $t {
+                self.redc(a, 0)
+            }
+
+            /// Multiply `a` and `b`, modulo `m`
+            pub fn mul_mod(&self, a: $t, b: $t) -> $t {
+                // (a·b·R⁻¹)·(R²)·R⁻¹ = a·b
+                self.mul(self.mul(a % self.m, b % self.m), self.r2)
+            }
+
+            /// Raise `base` to the power `n`, modulo `m`
+            pub fn pow_mod<N>(&self, base: $t, n: N) -> $t
+            where
+                N: PrimInt + Unsigned + ConstOne + ConstZero,
+            {
+                let mut result = self.r1;
+                let mut temp_exp = self.to_montgomery(base);
+                let mut n_work = n;
+                while n_work != N::ZERO {
+                    if n_work & N::ONE != N::ZERO {
+                        result = self.mul(result, temp_exp);
+                    }
+                    n_work = n_work >> 1;
+                    temp_exp = self.mul(temp_exp, temp_exp);
+                }
+                self.from_montgomery(result)
+            }
+        }
+    };
+}
+
+montgomery_impl!(u64, mul_wide_u64);
+montgomery_impl!(u128, mul_wide_u128);
+
+/**************************************/
+/*          Const functions           */
+/**************************************/
+
+// The generic functions above can't be `const fn`, because they use num-traits trait methods.
+// These are concrete-type versions that can be evaluated at compile time, eg to jump a generator
+// by a fixed offset. The exponent `n` is always a u128.
+
+/// `const fn` version of `mul_mod()` for u128, in the same way as `mul_mod_generic()`.
+///
+///     use ssrand::math::mul_mod_u128;
+///     const RESULT: u128 = mul_mod_u128(u128::MAX - 1, u128::MAX - 1, u128::MAX);
+///     assert_eq!(RESULT, 1);
+///
+pub const fn mul_mod_u128(a: u128, b: u128, m: u128) -> u128 {
+    let mut a_work = a;
+    let mut b_work = b % m;
+    let mut result = 0;
+    while a_work != 0 {
+        if a_work & 1 != 0 {
+            if b_work >= m - result {
+                result = result.wrapping_sub(m);
+            }
+            result = result.wrapping_add(b_work);
+        }
+        a_work >>= 1;
+
+        let mut temp_b = b_work;
+        if b_work >= m - temp_b {
+            temp_b = temp_b.wrapping_sub(m);
+        }
+        b_work = b_work.wrapping_add(temp_b);
+    }
+    result
+}
+
+/// `const fn` version of `mul_mod()` for u32, using u64 for the intermediate product.
+pub const fn mul_mod_u32(a: u32, b: u32, m: u32) -> u32 {
+    ((a as u64) * (b as u64) % (m as u64)) as u32
+}
+
+/// `const fn` version of `mul_mod()` for u64, using u128 for the intermediate product.
+pub const fn mul_mod_u64(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128) * (b as u128) % (m as u128)) as u64
+}
+
+macro_rules! const_pow_fns {
+    ($t:ty, $mul_mod:ident, $pow_mod:ident, $wrapping_pow:ident, $wrapping_geom_series:ident) => {
+        /// `const fn` version of `pow_mod()`.
+        pub const fn $pow_mod(base: $t, n: u128, m: $t) -> $t {
+            let mut result: $t = 1 % m;
+            let mut temp_exp = base % m;
+            let mut n_work = n;
+            while n_work != 0 {
+                if n_work & 1 != 0 {
+                    result = $mul_mod(result, temp_exp, m);
+                }
+                n_work >>= 1;
+                temp_exp = $mul_mod(temp_exp, temp_exp, m);
+            }
+            result
+        }
+
+        /// `const fn` version of `wrapping_pow()`.
+        pub const fn $wrapping_pow(base: $t, n: u128) -> $t {
+            let mut result: $t = 1;
+            let mut temp_exp = base;
+            let mut n_work = n;
+            while n_work != 0 {
+                if n_work & 1 != 0 {
+                    result = result.wrapping_mul(temp_exp);
+                }
+                n_work >>= 1;
+                temp_exp = temp_exp.wrapping_mul(temp_exp);
+            }
+            result
+        }
+
+        /// `const fn` version of `wrapping_geom_series()`.
+        pub const fn $wrapping_geom_series(r: $t, n: u128) -> $t {
+            let mut temp_r = r;
+            let mut mult: $t = 1;
+            let mut result: $t = 0;
+
+            if n == 0 {
+                return 0;
+            }
+
+            let mut n_work = n;
+            while n_work > 1 {
+                if n_work & 1 != 0 {
+                    result = $wrapping_pow(temp_r, n_work - 1)
+                        .wrapping_mul(mult)
+                        .wrapping_add(result);
+                }
+                mult = (1 as $t).wrapping_add(temp_r).wrapping_mul(mult);
+                temp_r = temp_r.wrapping_mul(temp_r);
+                n_work >>= 1;
+            }
+            result.wrapping_add(mult)
+        }
+    };
+}
+
+const_pow_fns!(
+    u32,
+    mul_mod_u32,
+    pow_mod_u32,
+    wrapping_pow_u32,
+    wrapping_geom_series_u32
+);
+const_pow_fns!(
+    u64,
+    mul_mod_u64,
+    pow_mod_u64,
+    wrapping_pow_u64,
+    wrapping_geom_series_u64
+);
+const_pow_fns!(
+    u128,
+    mul_mod_u128,
+    pow_mod_u128,
+    wrapping_pow_u128,
+    wrapping_geom_series_u128
+);
@@ -0,0 +1,130 @@
+//! Search for multiply-with-carry multipliers
+//!
+//! A lag-r MWC generator with base b = 2^w (the word size) and multiplier a has modulus
+//! p = a·b^r - 1. It is equivalent to a multiplicative congruential generator modulo p, with
+//! multiplier b⁻¹ mod p = a·b^(r-1), and its period is the multiplicative order of b modulo p.
+//! If p is a safe prime, ie p = 2q + 1 with q prime, that order is q or 2q, so the period is
+//! about p/2 or more. Eg `MWC2` uses w = 16, r = 1 and a = 36969 or 18000.
+//!
+//! [`MwcSearch`] enumerates the multipliers a < b, largest first (for the longest period), that
+//! give a safe-prime modulus. Each candidate is scored by the [spectral test](crate::spectral) of
+//! the equivalent congruential generator in dimensions 2 to 8, so that a caller can rank them.
+//!
+//! For a lag above 1, the dual lattice always contains the vector (-1, b), so in two dimensions
+//! successive outputs lie on about b lines, and every multiplier scores poorly.
+
+use crate::math;
+use crate::spectral::{self, SpectralTest};
+
+/// A multiplier found by [`MwcSearch`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MwcCandidate {
+    /// The multiplier a
+    pub multiplier: u64,
+    /// The modulus a·b^r - 1, a safe prime
+    pub modulus: u64,
+    /// Period of the generator, ie the multiplicative order of b modulo the modulus
+    pub period: u64,
+    /// Spectral test of the equivalent congruential generator, up to
+    /// [`MAX_DIMENSION`](crate::spectral::MAX_DIMENSION)
+    pub spectral: SpectralTest,
+    /// The worst normalised figure of merit S_t over all dimensions, in (0, 1]. Higher is better.
+    pub score: f64,
+}
+
+/// Iterator over MWC multipliers that give a safe-prime modulus
+///
+/// Multipliers are tried from b - 1 downwards, and those for which a·b^r - 1 is a safe prime
+/// are returned, with their period and spectral score.
+///
+///     use ssrand::mwcsearch::MwcSearch;
+///     // The largest multiplier for an MWC with 16-bit words.
+///     let candidate = MwcSearch::new(16, 1).next().unwrap();
+///     assert_eq!(candidate.multiplier, 65184);
+///     assert_eq!(candidate.modulus, (65184 << 16) - 1);
+///
+#[derive(Clone, Debug)]
+pub struct MwcSearch {
+    word_bits: u32,
+    lag: u32,
+    /// The next multiplier to try
+    multiplier: u64,
+}
+
+impl MwcSearch {
+    /// Search for multipliers of a lag-`lag` MWC with `word_bits`-bit words
+    ///
+    /// The modulus is limited to 62 bits, so for large words only multipliers below
+    /// 2^(62 - word_bits·lag) are searched. Eg for `MWC64`, with 32-bit words and lag 1,
+    /// multipliers up to 2^30.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word_bits` is less than 2, `lag` is zero, or `word_bits·lag > 60`.
+    pub fn new(word_bits: u32, lag: u32) -> MwcSearch {
+        assert!(word_bits >= 2, "word size is too small");
+        assert!(lag >= 1, "lag must be at least 1");
+        assert!(
+            word_bits.checked_mul(lag).is_some_and(|b| b <= 60),
+            "modulus is too large for the search"
+        );
+        MwcSearch {
+            word_bits,
+            lag,
+            multiplier: MwcSearch::max_multiplier(word_bits, lag),
+        }
+    }
+
+    /// Largest multiplier to search: less than the base, with the modulus less than 2^62.
+    fn max_multiplier(word_bits: u32, lag: u32) -> u64 {
+        let base_limit = (1_u64 << word_bits) - 1;
+        let modulus_limit = (1_u64 << (62 - word_bits * lag)) - 1;
+        base_limit.min(modulus_limit)
+    }
+
+    /// Evaluate a multiplier, if it gives a safe-prime modulus (of at most 62 bits).
+    pub fn candidate(&self, multiplier: u64) -> Option<MwcCandidate> {
+        let shift = (self.word_bits * self.lag) as usize;
+        if multiplier > MwcSearch::max_multiplier(self.word_bits, self.lag)
+            || !math::is_mwc_multiplier(multiplier, shift)
+        {
+            return None;
+        }
+        let modulus = (multiplier << shift) - 1;
+        let base = 1_u64 << self.word_bits;
+        let q = (modulus - 1) / 2;
+        let period = if math::pow_mod(base, q, modulus) == 1 {
+            q
+        } else {
+            modulus - 1
+        };
+        // Multiplier of the equivalent congruential generator, b⁻¹ = a·b^(r-1).
+        let lambda = multiplier << (self.word_bits * (self.lag - 1));
+        let spectral =
+            spectral::spectral_test(lambda as u128, modulus as u128, spectral::MAX_DIMENSION)
+                .expect("a modulus of 62 bits can't overflow");
+        let score = spectral.min_score();
+        Some(MwcCandidate {
+            multiplier,
+            modulus,
+            period,
+            spectral,
+            score,
+        })
+    }
+}
+
+impl Iterator for MwcSearch {
+    type Item = MwcCandidate;
+
+    fn next(&mut self) -> Option<MwcCandidate> {
+        while self.multiplier >= 2 {
+            let multiplier = self.multiplier;
+            self.multiplier -= 1;
+            if let Some(candidate) = self.candidate(multiplier) {
+                return Some(candidate);
+            }
+        }
+        None
+    }
+}
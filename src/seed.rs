@@ -0,0 +1,188 @@
+//! Seeding
+//!
+//! Each generator's `new()` accepts any seeds. A degenerate seed, which would put part of the
+//! state at a fixed point (eg a zero `SHR3` state), is silently replaced when the generator is
+//! used, except for a few MWC seeds which stay stuck (see
+//! [`MWC2::try_new()`](crate::MWC2::try_new)). Each generator's `try_new()` reports a degenerate
+//! seed instead, as a [`SeedError`], for when the seeds must be used as given, eg for
+//! reproducibility audits.
+//!
+//! A seed is only reported if it gives a fixed point. A seed that is merely reduced, eg an MWC
+//! state above the modulus, gives an equivalent state, and is accepted.
+//!
+//! Seeds like 1, 2, 3, 4 are poor: most of their bits are zero, and they are similar to each
+//! other. [`SeedSequence`] expands a single `u64`, or a byte string of any length, into as many
+//! well-mixed seed words as a generator needs, and skips degenerate seeds. Its output is fully
+//! specified, so ports to other languages can match it. Every generator
+//! implements [`FromSeedSequence`], and `rand_core::SeedableRng` using it:
+//!
+//!     use rand_core::{RngCore, SeedableRng};
+//!     use ssrand::seed::{FromSeedSequence, SeedSequence};
+//!     use ssrand::KISS;
+//!
+//!     let mut rng = KISS::seed_from_u64(42);
+//!     let mut rng_ref = KISS::from_seed_sequence(&mut SeedSequence::from_u64(42));
+//!     assert_eq!(rng.next_u32(), rng_ref.next_u32());
+//!
+
+use core::fmt;
+
+/// A degenerate seed, reported by a generator's `try_new()`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SeedError {
+    /// The generator type being seeded, eg "KISS"
+    pub generator: &'static str,
+    /// The degenerate part of the state, eg "MWC2 upper", "SHR3" or "z3"
+    pub component: &'static str,
+    /// Which seed the component comes from: 1 for `seed1`, and so on. The MWC64 state is made from
+    /// two seeds, and this is the first of them.
+    pub seed: usize,
+    /// The degenerate state
+    pub value: u64,
+    /// What `new()` replaces the state with, when the generator is used, or `None` if the
+    /// replacement is still a fixed point, so the component is stuck
+    pub replacement: Option<u64>,
+}
+
+impl fmt::Display for SeedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} seed{} gives degenerate {} state 0x{:X}, ",
+            self.generator, self.seed, self.component, self.value
+        )?;
+        match self.replacement {
+            Some(replacement) => write!(f, "which would be replaced by 0x{:X}", replacement),
+            None => write!(f, "which can't be replaced"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SeedError {}
+
+/* SeedSequence --------------------------------------------------------------*/
+
+/// Expands a `u64` or a byte string into seed words for the generators
+///
+/// The entropy is held in a pool of up to 8 32-bit words, which is stirred with [`mix32()`] to
+/// give the seed words. Every step is a bijection, so for entropy of at most 32 bytes, different
+/// entropy of the same length gives different seed words, and the first seed words are as many
+/// as the entropy words. So eg `KISS::from_seed()` gives a different generator for each of its
+/// 2^128 seeds, apart from the rare sets of seed words skipped as degenerate.
+///
+/// In detail, [`from_bytes()`](Self::from_bytes) takes the bytes 4 at a time as little-endian
+/// words `w[k]`, with the last word zero-padded, and with `W` words in all. The pool size is
+/// `n = min(max(W, 1), 8)`. Then, with wrapping arithmetic on `u32`:
+///
+/// 1. `pool[i] = len + (i + 1) * 0x9E3779B9` for `i` in `0..n`, where `len` is the byte
+///    length.
+/// 2. For each word in order, `pool[k % n] = mix32(pool[k % n] ^ w[k])`.
+/// 3. The pool is stirred: twice, for `i` in `0..n` in order,
+///    `pool[i] = mix32(pool[i] ^ pool[(i + n - 1) % n])`, except that for `n = 1` it is
+///    `pool[0] = mix32(pool[0])`.
+///
+/// The seed words are then `pool[0]` to `pool[n - 1]`. When they are used up, the `r`th refill
+/// (counting from 1) does `pool[0] ^= r`, stirs the pool again, and continues from `pool[0]`.
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct SeedSequence {
+    pool: [u32; SeedSequence::POOL_SIZE],
+    size: usize,
+    index: usize,
+    refills: u32,
+}
+
+/// A bijective 32-bit hash, `lowbias32` by Chris Wellons
+///
+/// `x ^= x >> 16; x *= 0x7FEB352D; x ^= x >> 15; x *= 0x846CA68B; x ^= x >> 16`, with wrapping
+/// multiplication. Each step can be undone, so different inputs give different outputs.
+///
+/// "Prospecting for Hash Functions"
+/// C. Wellons
+/// <https://nullprogram.com/blog/2018/07/31/>
+pub const fn mix32(x: u32) -> u32 {
+    let x = (x ^ (x >> 16)).wrapping_mul(0x7FEB352D);
+    let x = (x ^ (x >> 15)).wrapping_mul(0x846CA68B);
+    x ^ (x >> 16)
+}
+
+impl SeedSequence {
+    const POOL_SIZE: usize = 8;
+
+    /// Seed the sequence from a `u64`, as for its 8 little-endian bytes
+    pub fn from_u64(seed: u64) -> SeedSequence {
+        SeedSequence::from_bytes(&seed.to_le_bytes())
+    }
+
+    /// Seed the sequence from entropy of any length. See [`SeedSequence`] for the algorithm.
+    pub fn from_bytes(bytes: &[u8]) -> SeedSequence {
+        let word_count = bytes.len().div_ceil(4);
+        let size = word_count.clamp(1, SeedSequence::POOL_SIZE);
+        let mut pool = [0; SeedSequence::POOL_SIZE];
+        for (i, word) in pool[..size].iter_mut().enumerate() {
+            *word = (bytes.len() as u32).wrapping_add((i as u32 + 1).wrapping_mul(0x9E3779B9));
+        }
+        for (k, chunk) in bytes.chunks(4).enumerate() {
+            let mut word = [0; 4];
+            word[..chunk.len()].copy_from_slice(chunk);
+            pool[k % size] = mix32(pool[k % size] ^ u32::from_le_bytes(word));
+        }
+        let mut seq = SeedSequence {
+            pool,
+            size,
+            index: 0,
+            refills: 0,
+        };
+        seq.stir();
+        seq
+    }
+
+    /// Two rounds of mixing each pool word with the one before it.
+    fn stir(&mut self) {
+        let n = self.size;
+        for _ in 0..2 {
+            for i in 0..n {
+                let prev = if n > 1 { self.pool[(i + n - 1) % n] } else { 0 };
+                self.pool[i] = mix32(self.pool[i] ^ prev);
+            }
+        }
+    }
+
+    /// The next seed word
+    pub fn next_seed(&mut self) -> u32 {
+        if self.index == self.size {
+            self.refills = self.refills.wrapping_add(1);
+            self.pool[0] ^= self.refills;
+            self.stir();
+            self.index = 0;
+        }
+        self.index += 1;
+        self.pool[self.index - 1]
+    }
+
+    /// Create a generator from the next `N` seed words, using its `try_new()`
+    ///
+    /// If `try_new` reports a degenerate seed, the whole set of words is discarded and the next
+    /// `N` words are tried, so the generator never relies on `new()` replacing a seed.
+    pub fn generate<T, F, const N: usize>(&mut self, try_new: F) -> T
+    where
+        F: Fn([u32; N]) -> Result<T, SeedError>,
+    {
+        loop {
+            let mut seeds = [0; N];
+            for seed in seeds.iter_mut() {
+                *seed = self.next_seed();
+            }
+            if let Ok(rng) = try_new(seeds) {
+                return rng;
+            }
+        }
+    }
+}
+
+/// Generators which can be created from a [`SeedSequence`]
+pub trait FromSeedSequence: Sized {
+    /// Create a generator from the next seed words of `seq`, skipping degenerate seeds. See
+    /// [`SeedSequence::generate()`].
+    fn from_seed_sequence(seq: &mut SeedSequence) -> Self;
+}
@@ -0,0 +1,560 @@
+//! Spectral test of linear congruential generators
+//!
+//! The spectral test (Knuth, TAOCP volume 2, section 3.3.4) measures the lattice structure of a
+//! linear congruential generator `x[n+1] = (a·x[n] + c) mod m`. In t dimensions, successive
+//! t-tuples of outputs, scaled to the unit cube, lie on families of parallel hyperplanes, and
+//! 1/ν_t is the greatest distance between adjacent hyperplanes of any family. Equivalently, ν_t
+//! is the length of the shortest non-zero integer vector (s₁, …, s_t) with
+//! `s₁ + a·s₂ + … + a^(t-1)·s_t = 0 mod m`. A larger ν_t is better.
+//!
+//! [`spectral_test()`] computes ν_t² exactly for t from 2 up to 8, by Knuth's Algorithm S, and
+//! gives two figures of merit derived from it:
+//!
+//! * μ_t, Knuth's figure of merit, the volume of the t-dimensional ellipsoid of radius ν_t, over
+//!   m. Knuth suggests μ_t ≥ 0.1 passes, and μ_t ≥ 1 is very good.
+//! * S_t, ν_t normalised by its largest possible value for modulus m, from Hermite's constant.
+//!   It is in (0, 1], and higher is better. L'Ecuyer's tables of good multipliers use this.
+//!
+//! The arithmetic is exact, in 128-bit integers where they suffice, and otherwise in 256-bit
+//! integers, so moduli up to 2^64 and somewhat beyond, such as that of a 64-bit LCG, can be
+//! tested.
+//!
+//! This also applies to multiply-with-carry generators, through their equivalent congruential
+//! generator. See [`mwcsearch`](crate::mwcsearch).
+
+use crate::math::{self, BigUint};
+use core::convert::TryFrom;
+use core::fmt;
+
+/// Largest dimension supported by [`spectral_test()`]
+pub const MAX_DIMENSION: usize = 8;
+
+/// Hermite's constants γ_t, for t up to 8. The densest lattice packing in t dimensions has
+/// shortest vector length ν_t = γ_t^(1/2)·m^(1/t), for a lattice of determinant m.
+const HERMITE: [f64; MAX_DIMENSION + 1] = [
+    0.0,
+    1.0,
+    1.1547005383792515,        // (4/3)^(1/2)
+    1.2599210498948732,        // 2^(1/3)
+    core::f64::consts::SQRT_2, // 2^(1/2)
+    1.5157165665103982,        // 8^(1/5)
+    1.6653663553112103,        // (64/3)^(1/6)
+    1.8114473285278132,        // 64^(1/7)
+    2.0,
+];
+
+/// Volume of the t-dimensional unit ball, ie π^(t/2)/(t/2)!, for t up to 8.
+const UNIT_BALL_VOLUME: [f64; MAX_DIMENSION + 1] = [
+    1.0,
+    2.0,
+    core::f64::consts::PI, // π
+    4.1887902047863905,    // 4π/3
+    4.934802200544679,     // π²/2
+    5.263789013914324,     // 8π²/15
+    5.16771278004997,      // π³/6
+    4.724765970331401,     // 16π³/105
+    4.058712126416768,     // π⁴/24
+];
+
+/// Results of the spectral test, for dimensions 2 up to a maximum
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SpectralTest {
+    multiplier: u128,
+    modulus: u128,
+    max_dimension: usize,
+    /// ν_t², indexed by t
+    nu_squared: [u128; MAX_DIMENSION + 1],
+}
+
+impl SpectralTest {
+    /// The multiplier a, reduced modulo m
+    pub fn multiplier(&self) -> u128 {
+        self.multiplier
+    }
+
+    /// The modulus m
+    pub fn modulus(&self) -> u128 {
+        self.modulus
+    }
+
+    /// The largest dimension tested
+    pub fn max_dimension(&self) -> usize {
+        self.max_dimension
+    }
+
+    fn check_dimension(&self, t: usize) {
+        assert!(
+            (2..=self.max_dimension).contains(&t),
+            "dimension {} was not tested",
+            t
+        );
+    }
+
+    /// ν_t², the squared length of the shortest vector in the dual lattice, in dimension `t`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `t` is less than 2 or more than `max_dimension()`.
+    pub fn nu_squared(&self, t: usize) -> u128 {
+        self.check_dimension(t);
+        self.nu_squared[t]
+    }
+
+    /// ν_t, the "accuracy" of the generator in dimension `t`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `t` is less than 2 or more than `max_dimension()`.
+    pub fn nu(&self, t: usize) -> f64 {
+        math::root_f64(self.nu_squared(t) as f64, 2)
+    }
+
+    /// μ_t, Knuth's figure of merit in dimension `t`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `t` is less than 2 or more than `max_dimension()`.
+    pub fn mu(&self, t: usize) -> f64 {
+        let nu = self.nu(t);
+        let mut nu_power = 1.0;
+        for _ in 0..t {
+            nu_power *= nu;
+        }
+        UNIT_BALL_VOLUME[t] * nu_power / self.modulus as f64
+    }
+
+    /// S_t, the normalised figure of merit in dimension `t`, in (0, 1]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `t` is less than 2 or more than `max_dimension()`.
+    pub fn score(&self, t: usize) -> f64 {
+        let m_root = math::root_f64(self.modulus as f64, t as u32);
+        math::root_f64(
+            self.nu_squared(t) as f64 / (HERMITE[t] * m_root * m_root),
+            2,
+        )
+    }
+
+    /// The worst S_t over all the dimensions tested
+    pub fn min_score(&self) -> f64 {
+        (2..=self.max_dimension)
+            .map(|t| self.score(t))
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+/// Error from [`spectral_test()`], when even 256-bit arithmetic overflowed
+///
+/// This can only happen for a modulus well beyond 2^64.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SpectralError {
+    /// The multiplier a, reduced modulo m
+    pub multiplier: u128,
+    /// The modulus m
+    pub modulus: u128,
+    /// The dimension being tested when the arithmetic overflowed
+    pub dimension: usize,
+}
+
+impl fmt::Display for SpectralError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "spectral test of multiplier {} modulo {} overflowed in dimension {}",
+            self.multiplier, self.modulus, self.dimension
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SpectralError {}
+
+/// Signed integers for Algorithm S
+///
+/// Every operation that can overflow returns `None` if it does.
+trait SpectralInt: Copy + Ord {
+    const ZERO: Self;
+    const ONE: Self;
+    fn from_u128(n: u128) -> Option<Self>;
+    fn to_u128(self) -> Option<u128>;
+    fn is_negative(self) -> bool;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+    fn checked_abs(self) -> Option<Self>;
+    /// Quotient and remainder, truncated towards zero as for `/` and `%`, for non-zero `rhs`
+    fn div_rem(self, rhs: Self) -> (Self, Self);
+    /// Integer square root, of a non-negative value
+    fn isqrt(self) -> Self;
+}
+
+impl SpectralInt for i128 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+    fn from_u128(n: u128) -> Option<Self> {
+        i128::try_from(n).ok()
+    }
+    fn to_u128(self) -> Option<u128> {
+        u128::try_from(self).ok()
+    }
+    fn is_negative(self) -> bool {
+        self < 0
+    }
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        i128::checked_add(self, rhs)
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        i128::checked_sub(self, rhs)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        i128::checked_mul(self, rhs)
+    }
+    fn checked_neg(self) -> Option<Self> {
+        i128::checked_neg(self)
+    }
+    fn checked_abs(self) -> Option<Self> {
+        i128::checked_abs(self)
+    }
+    fn div_rem(self, rhs: Self) -> (Self, Self) {
+        (self / rhs, self % rhs)
+    }
+    fn isqrt(self) -> Self {
+        math::isqrt(self as u128) as i128
+    }
+}
+
+/// Signed 256-bit integer, as a sign and magnitude, for when `i128` overflows
+///
+/// Zero is never negative.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct I256 {
+    negative: bool,
+    magnitude: BigUint<4>,
+}
+
+impl I256 {
+    fn new(negative: bool, magnitude: BigUint<4>) -> Self {
+        I256 {
+            negative: negative && magnitude != BigUint::ZERO,
+            magnitude,
+        }
+    }
+}
+
+impl PartialOrd for I256 {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for I256 {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (negative, _) => other.negative.cmp(&negative),
+        }
+    }
+}
+
+impl SpectralInt for I256 {
+    const ZERO: Self = I256 {
+        negative: false,
+        magnitude: BigUint::ZERO,
+    };
+    const ONE: Self = I256 {
+        negative: false,
+        magnitude: BigUint::from_limbs([1, 0, 0, 0]),
+    };
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(I256::new(false, BigUint::from_u128(n)))
+    }
+    fn to_u128(self) -> Option<u128> {
+        match self.magnitude.limbs() {
+            [low, high, 0, 0] if !self.negative => Some(((high as u128) << 64) | low as u128),
+            _ => None,
+        }
+    }
+    fn is_negative(self) -> bool {
+        self.negative
+    }
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        if self.negative == rhs.negative {
+            let magnitude = self.magnitude.checked_add(rhs.magnitude)?;
+            Some(I256::new(self.negative, magnitude))
+        } else if self.magnitude >= rhs.magnitude {
+            let magnitude = self.magnitude.checked_sub(rhs.magnitude)?;
+            Some(I256::new(self.negative, magnitude))
+        } else {
+            let magnitude = rhs.magnitude.checked_sub(self.magnitude)?;
+            Some(I256::new(rhs.negative, magnitude))
+        }
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let magnitude = self.magnitude.checked_mul(rhs.magnitude)?;
+        Some(I256::new(self.negative != rhs.negative, magnitude))
+    }
+    fn checked_neg(self) -> Option<Self> {
+        Some(I256::new(!self.negative, self.magnitude))
+    }
+    fn checked_abs(self) -> Option<Self> {
+        Some(I256::new(false, self.magnitude))
+    }
+    fn div_rem(self, rhs: Self) -> (Self, Self) {
+        let (quotient, remainder) = self
+            .magnitude
+            .checked_div_rem(rhs.magnitude)
+            .expect("division by zero in spectral test");
+        (
+            I256::new(self.negative != rhs.negative, quotient),
+            I256::new(self.negative, remainder),
+        )
+    }
+    fn isqrt(self) -> Self {
+        I256::new(false, self.magnitude.isqrt())
+    }
+}
+
+/// A vector of up to `MAX_DIMENSION` elements, of which the first t are used.
+type Vector<I> = [I; MAX_DIMENSION];
+
+fn dot<I: SpectralInt>(u: &Vector<I>, v: &Vector<I>, t: usize) -> Option<I> {
+    u[..t]
+        .iter()
+        .zip(v[..t].iter())
+        .try_fold(I::ZERO, |sum, (&x, &y)| sum.checked_add(x.checked_mul(y)?))
+}
+
+/// `u += q·v`, for the first t elements.
+fn add_multiple<I: SpectralInt>(u: &mut Vector<I>, v: &Vector<I>, q: I, t: usize) -> Option<()> {
+    for (x, &y) in u[..t].iter_mut().zip(v[..t].iter()) {
+        *x = x.checked_add(q.checked_mul(y)?)?;
+    }
+    Some(())
+}
+
+/// `a / b` rounded to the nearest integer, for `b > 0`.
+fn round_div<I: SpectralInt>(a: I, b: I) -> Option<I> {
+    let (quotient, remainder) = a.div_rem(b);
+    let remainder_abs = remainder.checked_abs()?;
+    if remainder_abs > b.checked_sub(remainder_abs)? {
+        if remainder.is_negative() {
+            quotient.checked_sub(I::ONE)
+        } else {
+            quotient.checked_add(I::ONE)
+        }
+    } else {
+        Some(quotient)
+    }
+}
+
+/// `x² + y²`
+fn sum_squares<I: SpectralInt>(x: I, y: I) -> Option<I> {
+    x.checked_mul(x)?.checked_add(y.checked_mul(y)?)
+}
+
+/// Spectral test of the multiplier `multiplier` modulo `modulus`, in dimensions 2 to
+/// `max_dimension`
+///
+/// The increment of the generator doesn't matter. For a multiply-with-carry generator, use the
+/// multiplier and modulus of its equivalent congruential generator.
+///
+/// This is Knuth's Algorithm S, with exact integer arithmetic. The time taken grows quickly with
+/// the dimension, but is typically well under a second for 8 dimensions. It's 128-bit
+/// arithmetic for moduli up to about 2^62, and 256-bit arithmetic, which is slower, beyond.
+///
+///     use ssrand::spectral::spectral_test;
+///     // RANDU: 9·x[n] - 6·x[n+1] + x[n+2] = 0 mod 2^31, so all triples lie on 15 planes.
+///     let randu = spectral_test(65539, 1 << 31, 3).unwrap();
+///     assert_eq!(randu.nu_squared(3), 118);
+///     assert!(randu.mu(3) < 1e-5);
+///
+/// Returns an error if the 256-bit arithmetic overflows, which can only happen for a modulus
+/// well beyond 2^64.
+///
+/// # Panics
+///
+/// Panics if `max_dimension` is not from 2 to 8, `modulus` is less than 2, or `multiplier` is
+/// 0 modulo `modulus`.
+pub fn spectral_test(
+    multiplier: u128,
+    modulus: u128,
+    max_dimension: usize,
+) -> Result<SpectralTest, SpectralError> {
+    assert!(
+        (2..=MAX_DIMENSION).contains(&max_dimension),
+        "dimension must be from 2 to {}",
+        MAX_DIMENSION
+    );
+    assert!(modulus >= 2, "modulus must be at least 2");
+    let a = multiplier % modulus;
+    assert!(a != 0, "multiplier is 0 modulo the modulus");
+
+    let mut result = SpectralTest {
+        multiplier: a,
+        modulus,
+        max_dimension,
+        nu_squared: [0; MAX_DIMENSION + 1],
+    };
+    if algorithm_s::<i128>(a, modulus, max_dimension, &mut result.nu_squared).is_none() {
+        result.nu_squared = [0; MAX_DIMENSION + 1];
+        if algorithm_s::<I256>(a, modulus, max_dimension, &mut result.nu_squared).is_none() {
+            let dimension = (2..=max_dimension)
+                .find(|&t| result.nu_squared[t] == 0)
+                .unwrap_or(max_dimension);
+            return Err(SpectralError {
+                multiplier: a,
+                modulus,
+                dimension,
+            });
+        }
+    }
+    Ok(result)
+}
+
+/// Knuth's Algorithm S, in integers of type `I`
+///
+/// Sets `nu_squared[t]` for each dimension t in turn, and returns `None` if the arithmetic
+/// overflows.
+fn algorithm_s<I: SpectralInt>(
+    a: u128,
+    m: u128,
+    max_dimension: usize,
+    nu_squared: &mut [u128; MAX_DIMENSION + 1],
+) -> Option<()> {
+    let a = I::from_u128(a)?;
+    let m = I::from_u128(m)?;
+
+    // S1, S2: Euclidean steps, to find ν₂ in two dimensions.
+    let (mut h, mut h_prime, mut p, mut p_prime) = (a, m, I::ONE, I::ZERO);
+    let mut s = sum_squares(I::ONE, a)?;
+    let (mut u, mut v);
+    loop {
+        let (q, _) = h_prime.div_rem(h);
+        u = h_prime.checked_sub(q.checked_mul(h)?)?;
+        v = p_prime.checked_sub(q.checked_mul(p)?)?;
+        let length = sum_squares(u, v)?;
+        if length < s {
+            s = length;
+            h_prime = h;
+            h = u;
+            p_prime = p;
+            p = v;
+        } else {
+            break;
+        }
+    }
+    // S3
+    u = u.checked_sub(h)?;
+    v = v.checked_sub(p)?;
+    let length = sum_squares(u, v)?;
+    if length < s {
+        s = length;
+        h_prime = u;
+        p_prime = v;
+    }
+    nu_squared[2] = s.to_u128()?;
+
+    // U and V are dual bases: U_i·V_j = m when i = j, and 0 otherwise.
+    let mut u_rows: [Vector<I>; MAX_DIMENSION] = [[I::ZERO; MAX_DIMENSION]; MAX_DIMENSION];
+    let mut v_rows: [Vector<I>; MAX_DIMENSION] = [[I::ZERO; MAX_DIMENSION]; MAX_DIMENSION];
+    u_rows[0][..2].copy_from_slice(&[h.checked_neg()?, p]);
+    u_rows[1][..2].copy_from_slice(&[h_prime.checked_neg()?, p_prime]);
+    // p·h' - h·p' is ±m. Choose the sign of V so that U_i·V_i = m.
+    let determinant = p
+        .checked_mul(h_prime)?
+        .checked_sub(h.checked_mul(p_prime)?)?;
+    let signed = |x: I| {
+        if determinant.is_negative() {
+            x.checked_neg()
+        } else {
+            Some(x)
+        }
+    };
+    v_rows[0][..2].copy_from_slice(&[signed(p_prime)?, signed(h_prime)?]);
+    v_rows[1][..2].copy_from_slice(&[signed(p)?.checked_neg()?, signed(h)?.checked_neg()?]);
+
+    let mut r = a;
+    for t in 3..=max_dimension {
+        let last = t - 1;
+        // S4: add a dimension.
+        r = a.checked_mul(r)?.div_rem(m).1;
+        u_rows[last] = [I::ZERO; MAX_DIMENSION];
+        u_rows[last][0] = r.checked_neg()?;
+        u_rows[last][last] = I::ONE;
+        v_rows[last] = [I::ZERO; MAX_DIMENSION];
+        v_rows[last][last] = m;
+        for i in 0..last {
+            let v_r = v_rows[i][0].checked_mul(r)?;
+            let q = round_div(v_r, m)?;
+            v_rows[i][last] = v_r.checked_sub(q.checked_mul(m)?)?;
+            let u_i = u_rows[i];
+            add_multiple(&mut u_rows[last], &u_i, q, t)?;
+        }
+        s = s.min(dot(&u_rows[last], &u_rows[last], t)?);
+
+        // S5, S6: transform the bases until no transformation reduces them.
+        let mut k = last;
+        let mut j = 0;
+        loop {
+            let vj_vj = dot(&v_rows[j], &v_rows[j], t)?;
+            for i in (0..t).filter(|&i| i != j) {
+                let vi_vj = dot(&v_rows[i], &v_rows[j], t)?;
+                let vi_vj_abs = vi_vj.checked_abs()?;
+                if vi_vj_abs > vj_vj.checked_sub(vi_vj_abs)? {
+                    let q = round_div(vi_vj, vj_vj)?;
+                    let v_j = v_rows[j];
+                    add_multiple(&mut v_rows[i], &v_j, q.checked_neg()?, t)?;
+                    let u_i = u_rows[i];
+                    add_multiple(&mut u_rows[j], &u_i, q, t)?;
+                    s = s.min(dot(&u_rows[j], &u_rows[j], t)?);
+                    k = j;
+                }
+            }
+            j = if j == last { 0 } else { j + 1 };
+            if j == k {
+                break;
+            }
+        }
+
+        // S7: bounds for an exhaustive search of short vectors Σ X_j·U_j. Any vector shorter
+        // than sqrt(s) has |X_j| <= sqrt(V_j·V_j·s)/m. Rounding up only makes the search longer.
+        let s_root = s.isqrt().checked_add(I::ONE)?;
+        let mut z = [I::ZERO; MAX_DIMENSION];
+        for (z_j, v_j) in z[..t].iter_mut().zip(v_rows.iter()) {
+            let v_root = dot(v_j, v_j, t)?.isqrt().checked_add(I::ONE)?;
+            *z_j = v_root.checked_mul(s_root)?.div_rem(m).0;
+        }
+
+        // S8 to S10: search all X with -z <= X <= z, and the first non-zero element positive.
+        let mut x = [I::ZERO; MAX_DIMENSION];
+        let mut y: Vector<I> = [I::ZERO; MAX_DIMENSION];
+        let mut k = last as isize;
+        while k >= 0 {
+            let ku = k as usize;
+            if x[ku] != z[ku] {
+                // S8: advance X_k.
+                x[ku] = x[ku].checked_add(I::ONE)?;
+                add_multiple(&mut y, &u_rows[ku], I::ONE, t)?;
+                // S9: reset the following elements to their lowest values.
+                for k2 in ku + 1..t {
+                    x[k2] = z[k2].checked_neg()?;
+                    let q = z[k2].checked_add(z[k2])?.checked_neg()?;
+                    add_multiple(&mut y, &u_rows[k2], q, t)?;
+                }
+                s = s.min(dot(&y, &y, t)?);
+                k = last as isize;
+            } else {
+                // S10: decrease k.
+                k -= 1;
+            }
+        }
+        nu_squared[t] = s.to_u128()?;
+    }
+    Some(())
+}
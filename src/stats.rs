@@ -0,0 +1,472 @@
+//! Statistical tests of random number generator output
+//!
+//! A small battery of classic empirical tests, mostly as described by Knuth in The Art of Computer
+//! Programming, Vol. 2, section 3.3.2, plus Marsaglia's birthday spacings test. They are enough to
+//! reproduce the gross failures mentioned in the generator documentation, such as SHR3 failing the
+//! binary rank test. They are not a substitute for a full battery such as TestU01 or PractRand.
+//!
+//! Each test takes any [`RngCore`], and returns a [`TestResult`] with the test statistic and its
+//! p-value. A p-value extremely close to 0 or 1 (eg below 10^-6 or above 1 - 10^-6) indicates a
+//! failure.
+//!
+//! Most tests use the high bits of each 32-bit output, since those are the bits most commonly
+//! used, eg when converting to floating point.
+//!
+//! This module needs the `std` feature.
+
+use crate::bitmatrix::BitMatrix;
+use core::fmt;
+use rand_core::RngCore;
+use std::vec;
+use std::vec::Vec;
+
+/// The result of a statistical test.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestResult {
+    /// Name of the test.
+    pub name: &'static str,
+    /// The test statistic, eg chi-square value.
+    pub statistic: f64,
+    /// Probability of a statistic at least as extreme as `statistic`, for truly random input.
+    pub p_value: f64,
+}
+
+impl fmt::Display for TestResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<18} statistic = {:<14.4} p = {:.6}",
+            self.name, self.statistic, self.p_value
+        )
+    }
+}
+
+/* Distribution functions ----------------------------------------------------*/
+
+/// Natural logarithm of the gamma function, by Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_039_169_991_85,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // Reflection formula.
+        let pi = core::f64::consts::PI;
+        (pi / (pi * x).sin()).ln() - ln_gamma(1.0 - x)
+    } else {
+        let x = x - 1.0;
+        let mut a = COEFFS[0];
+        for (i, c) in COEFFS.iter().enumerate().skip(1) {
+            a += c / (x + i as f64);
+        }
+        let t = x + 7.5;
+        0.5 * (2.0 * core::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + a.ln()
+    }
+}
+
+/// Regularised upper incomplete gamma function Q(a, x) = 1 - P(a, x).
+///
+/// Uses the series for P when x < a + 1, otherwise the continued fraction for Q.
+pub fn gamma_q(a: f64, x: f64) -> f64 {
+    const EPSILON: f64 = 1e-15;
+    const TINY: f64 = 1e-300;
+    const MAX_ITERATIONS: usize = 10_000;
+
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let ln_prefix = -x + a * x.ln() - ln_gamma(a);
+    if x < a + 1.0 {
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        let mut ap = a;
+        for _ in 0..MAX_ITERATIONS {
+            ap += 1.0;
+            term *= x / ap;
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+        1.0 - sum * ln_prefix.exp()
+    } else {
+        // Modified Lentz's method.
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / TINY;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..MAX_ITERATIONS {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = b + an / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < EPSILON {
+                break;
+            }
+        }
+        ln_prefix.exp() * h
+    }
+}
+
+/// p-value of a chi-square statistic with `dof` degrees of freedom.
+pub fn chi_square_p_value(chi_square: f64, dof: usize) -> f64 {
+    gamma_q(dof as f64 / 2.0, chi_square / 2.0)
+}
+
+/// Two-sided p-value of a standard normal statistic.
+pub fn normal_p_value(z: f64) -> f64 {
+    // erfc(|z|/sqrt(2)) = Q(1/2, z^2/2)
+    gamma_q(0.5, z * z / 2.0)
+}
+
+/// Two-sided p-value of an observed count `k` of a Poisson distribution with mean `lambda`.
+pub fn poisson_p_value(k: u64, lambda: f64) -> f64 {
+    // P(X <= k) = Q(k + 1, lambda), and P(X >= k) = 1 - P(X <= k - 1).
+    let p_le = gamma_q(k as f64 + 1.0, lambda);
+    let p_ge = if k == 0 {
+        1.0
+    } else {
+        1.0 - gamma_q(k as f64, lambda)
+    };
+    (2.0 * p_le.min(p_ge)).min(1.0)
+}
+
+/// Chi-square statistic of observed `counts` against expected `probabilities`.
+fn chi_square(counts: &[u64], probabilities: &[f64]) -> f64 {
+    let total: u64 = counts.iter().sum();
+    counts
+        .iter()
+        .zip(probabilities)
+        .map(|(&count, &p)| {
+            let expected = total as f64 * p;
+            let diff = count as f64 - expected;
+            diff * diff / expected
+        })
+        .sum()
+}
+
+fn chi_square_result(name: &'static str, counts: &[u64], probabilities: &[f64]) -> TestResult {
+    let statistic = chi_square(counts, probabilities);
+    TestResult {
+        name,
+        statistic,
+        p_value: chi_square_p_value(statistic, counts.len() - 1),
+    }
+}
+
+/* Tests ---------------------------------------------------------------------*/
+
+/// Frequency (equidistribution) test.
+///
+/// Counts the high 8 bits of `n` outputs in 256 categories, and compares to a uniform
+/// distribution. Chi-square with 255 degrees of freedom.
+pub fn frequency<R: RngCore + ?Sized>(rng: &mut R, n: usize) -> TestResult {
+    let mut counts = [0_u64; 256];
+    for _ in 0..n {
+        counts[(rng.next_u32() >> 24) as usize] += 1;
+    }
+    chi_square_result("frequency", &counts, &[1.0 / 256.0; 256])
+}
+
+/// Serial test.
+///
+/// Counts `n` non-overlapping pairs of successive outputs, using the high 4 bits of each, in 256
+/// categories. Chi-square with 255 degrees of freedom.
+pub fn serial<R: RngCore + ?Sized>(rng: &mut R, n: usize) -> TestResult {
+    let mut counts = [0_u64; 256];
+    for _ in 0..n {
+        let first = rng.next_u32() >> 28;
+        let second = rng.next_u32() >> 28;
+        counts[((first << 4) | second) as usize] += 1;
+    }
+    chi_square_result("serial", &counts, &[1.0 / 256.0; 256])
+}
+
+/// Gap test.
+///
+/// Measures the lengths of `n` gaps between outputs in the lower half of the range (high bit
+/// clear). Gap lengths 0 to 15 and ≥16 are counted, and compared to the geometric distribution.
+/// Chi-square with 16 degrees of freedom.
+pub fn gap<R: RngCore + ?Sized>(rng: &mut R, n: usize) -> TestResult {
+    const T: usize = 16;
+    let mut counts = [0_u64; T + 1];
+    for _ in 0..n {
+        let mut length = 0;
+        while rng.next_u32() >> 31 != 0 {
+            length += 1;
+        }
+        counts[length.min(T)] += 1;
+    }
+    let mut probabilities = [0.0; T + 1];
+    for (r, p) in probabilities.iter_mut().enumerate() {
+        // Probability of r consecutive misses, then a hit. The last category is all gaps ≥ T.
+        *p = 0.5_f64.powi(r as i32 + if r < T { 1 } else { 0 });
+    }
+    chi_square_result("gap", &counts, &probabilities)
+}
+
+/// Poker test.
+///
+/// Knuth's simplified poker test. Takes `n` groups of 5 successive outputs, using the high 3 bits
+/// of each, and counts the number of distinct values in each group. Groups with 1 or 2 distinct
+/// values are counted together. Chi-square with 3 degrees of freedom.
+pub fn poker<R: RngCore + ?Sized>(rng: &mut R, n: usize) -> TestResult {
+    let mut counts = [0_u64; 4];
+    for _ in 0..n {
+        let mut seen = 0_u8;
+        for _ in 0..5 {
+            seen |= 1 << (rng.next_u32() >> 29);
+        }
+        let distinct = seen.count_ones() as usize;
+        counts[distinct.max(2) - 2] += 1;
+    }
+    // Probability of r distinct values is S(5, r) 8!/(8-r)! / 8^5, where S is a Stirling number
+    // of the second kind.
+    let probabilities = [
+        (8.0 + 15.0 * 56.0) / 32768.0,
+        25.0 * 336.0 / 32768.0,
+        10.0 * 1680.0 / 32768.0,
+        6720.0 / 32768.0,
+    ];
+    chi_square_result("poker", &counts, &probabilities)
+}
+
+/// Birthday spacings test.
+///
+/// Marsaglia's birthday spacings test. In each of `samples` samples, 1024 birthdays are chosen in
+/// a year of 2^24 days, using the high 24 bits of each output. The number of repeated values among
+/// the spacings between sorted birthdays is approximately Poisson distributed with mean 16. The
+/// total over all samples is tested against the corresponding Poisson distribution.
+pub fn birthday_spacings<R: RngCore + ?Sized>(rng: &mut R, samples: usize) -> TestResult {
+    const BIRTHDAYS: usize = 1024;
+    const LAMBDA: f64 = 16.0; // BIRTHDAYS^3 / (4 * 2^24)
+    let mut birthdays = vec![0_u32; BIRTHDAYS];
+    let mut spacings = vec![0_u32; BIRTHDAYS];
+    let mut total = 0_u64;
+    for _ in 0..samples {
+        for birthday in birthdays.iter_mut() {
+            *birthday = rng.next_u32() >> 8;
+        }
+        birthdays.sort_unstable();
+        spacings[0] = birthdays[0];
+        for i in 1..BIRTHDAYS {
+            spacings[i] = birthdays[i] - birthdays[i - 1];
+        }
+        spacings.sort_unstable();
+        total += spacings.windows(2).filter(|w| w[0] == w[1]).count() as u64;
+    }
+    TestResult {
+        name: "birthday spacings",
+        statistic: total as f64,
+        p_value: poisson_p_value(total, LAMBDA * samples as f64),
+    }
+}
+
+/// Probability that a random `size`×`size` matrix over GF(2) has the given rank.
+///
+/// This is 2^(r(2n-r)-n²) ∏ (1-2^(i-n))² / (1-2^(i-r)), for i from 0 to r-1, where n is the size
+/// and r the rank.
+pub fn rank_probability(size: usize, rank: usize) -> f64 {
+    let n = size as i32;
+    let r = rank as i32;
+    let mut p = 2.0_f64.powi(r * (2 * n - r) - n * n);
+    for i in 0..r {
+        let a = 1.0 - 2.0_f64.powi(i - n);
+        p *= a * a / (1.0 - 2.0_f64.powi(i - r));
+    }
+    p
+}
+
+/// The observed distribution of ranks from a binary rank test, and its expected distribution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RankDistribution {
+    /// Size of the matrices, ie number of rows and columns.
+    pub size: usize,
+    /// Counts of matrices of rank ≤`size - 3`, `size - 2`, `size - 1` and `size`.
+    pub counts: [u64; 4],
+    /// Probabilities of those ranks, for random matrices.
+    pub probabilities: [f64; 4],
+    /// Chi-square test of the counts against the probabilities, with 3 degrees of freedom.
+    pub result: TestResult,
+}
+
+/// Binary matrix rank distribution.
+///
+/// Forms `n` `size`×`size` matrices over GF(2), each from the high `size` bits of `size`
+/// successive outputs, and counts their ranks.
+///
+/// # Arguments
+///
+/// * `size` - The matrix size, from 3 to 32. Marsaglia's Diehard uses 31 and 32.
+/// * `n` - The number of matrices.
+pub fn rank_distribution<R: RngCore + ?Sized>(
+    rng: &mut R,
+    size: usize,
+    n: usize,
+) -> RankDistribution {
+    assert!((3..=32).contains(&size), "matrix size must be 3 to 32");
+    // Smaller matrices are formed in the top-left of a 32×32 matrix, with the other columns
+    // zero, which doesn't change the rank.
+    let mut counts = [0_u64; 4];
+    let mut columns = [0_u32; 32];
+    for _ in 0..n {
+        for column in columns[..size].iter_mut() {
+            *column = rng.next_u32() >> (32 - size);
+        }
+        let rank = BitMatrix::<u32, 32>::new(&columns).rank();
+        counts[rank.max(size - 3) + 3 - size] += 1;
+    }
+    let mut probabilities = [0.0; 4];
+    for (i, p) in probabilities.iter_mut().enumerate().skip(1) {
+        *p = rank_probability(size, size - 3 + i);
+    }
+    probabilities[0] = 1.0 - probabilities[1..].iter().sum::<f64>();
+    RankDistribution {
+        size,
+        counts,
+        probabilities,
+        result: chi_square_result("binary rank", &counts, &probabilities),
+    }
+}
+
+/// Binary matrix rank test.
+///
+/// Forms `n` 32×32 matrices over GF(2) from 32 successive outputs each, and counts their ranks as
+/// ≤29, 30, 31 or 32. Compared to the rank distribution of random matrices. Chi-square with 3
+/// degrees of freedom. See [`rank_distribution`] for the details, and other sizes.
+pub fn binary_rank<R: RngCore + ?Sized>(rng: &mut R, n: usize) -> TestResult {
+    rank_distribution(rng, 32, n).result
+}
+
+/// The smallest `n` for [`runs()`]
+pub const RUNS_MIN_N: usize = 4000;
+
+/// Runs up test.
+///
+/// Compares `n` successive outputs, each with the one before, and counts the lengths of the
+/// ascending runs that end among them, as 1 to 5 and ≥6. This takes `n + 1` outputs. Since
+/// adjacent runs are not independent, the statistic is Knuth's quadratic form rather than a plain
+/// chi-square, but it has a chi-square distribution with 6 degrees of freedom.
+///
+/// That distribution is only approximate, for large `n`. Knuth suggests `n` of at least 4000,
+/// which is [`RUNS_MIN_N`].
+///
+/// # Panics
+///
+/// Panics if `n` is less than [`RUNS_MIN_N`].
+pub fn runs<R: RngCore + ?Sized>(rng: &mut R, n: usize) -> TestResult {
+    const A: [[f64; 6]; 6] = [
+        [4529.4, 9044.9, 13568.0, 18091.0, 22615.0, 27892.0],
+        [9044.9, 18097.0, 27139.0, 36187.0, 45234.0, 55789.0],
+        [13568.0, 27139.0, 40721.0, 54281.0, 67852.0, 83685.0],
+        [18091.0, 36187.0, 54281.0, 72414.0, 90470.0, 111580.0],
+        [22615.0, 45234.0, 67852.0, 90470.0, 113262.0, 139476.0],
+        [27892.0, 55789.0, 83685.0, 111580.0, 139476.0, 172860.0],
+    ];
+    const B: [f64; 6] = [
+        1.0 / 6.0,
+        5.0 / 24.0,
+        11.0 / 120.0,
+        19.0 / 720.0,
+        29.0 / 5040.0,
+        1.0 / 840.0,
+    ];
+    assert!(
+        n >= RUNS_MIN_N,
+        "runs test needs n of at least {}",
+        RUNS_MIN_N
+    );
+    let mut counts = [0_u64; 6];
+    let mut length = 1;
+    let mut previous = rng.next_u32();
+    for _ in 0..n {
+        let value = rng.next_u32();
+        if value > previous {
+            length += 1;
+        } else {
+            counts[length.min(6) - 1] += 1;
+            length = 1;
+        }
+        previous = value;
+    }
+    let mut statistic = 0.0;
+    for i in 0..6 {
+        for j in 0..6 {
+            statistic += (counts[i] as f64 - n as f64 * B[i])
+                * (counts[j] as f64 - n as f64 * B[j])
+                * A[i][j];
+        }
+    }
+    statistic /= n as f64 - 6.0;
+    TestResult {
+        name: "runs",
+        statistic,
+        p_value: chi_square_p_value(statistic, 6),
+    }
+}
+
+/// Collision test.
+///
+/// Throws 2^14 balls into 2^20 urns, using the high 20 bits of each output, and counts the
+/// collisions. Repeated for `samples` samples. The total is tested against a Poisson distribution
+/// with the expected number of collisions, approximately 128 per sample.
+pub fn collision<R: RngCore + ?Sized>(rng: &mut R, samples: usize) -> TestResult {
+    const URNS_BITS: u32 = 20;
+    const URNS: usize = 1 << URNS_BITS;
+    const BALLS: usize = 1 << 14;
+    let mut urns = vec![0_u64; URNS / 64];
+    let mut total = 0_u64;
+    for _ in 0..samples {
+        urns.iter_mut().for_each(|urn| *urn = 0);
+        for _ in 0..BALLS {
+            let urn = (rng.next_u32() >> (32 - URNS_BITS)) as usize;
+            let mask = 1_u64 << (urn % 64);
+            if urns[urn / 64] & mask != 0 {
+                total += 1;
+            } else {
+                urns[urn / 64] |= mask;
+            }
+        }
+    }
+    // Expected collisions are n - m + m(1 - 1/m)^n, for n balls into m urns.
+    let m = URNS as f64;
+    let expected = BALLS as f64 - m + m * (BALLS as f64 * (-1.0 / m).ln_1p()).exp();
+    TestResult {
+        name: "collision",
+        statistic: total as f64,
+        p_value: poisson_p_value(total, expected * samples as f64),
+    }
+}
+
+/// Run all the tests, with moderate sample sizes.
+///
+/// This uses about 21 million outputs of the generator.
+pub fn battery<R: RngCore + ?Sized>(rng: &mut R) -> Vec<TestResult> {
+    vec![
+        frequency(rng, 1_000_000),
+        serial(rng, 1_000_000),
+        gap(rng, 500_000),
+        poker(rng, 200_000),
+        birthday_spacings(rng, 500),
+        rank_distribution(rng, 31, 40_000).result,
+        binary_rank(rng, 40_000),
+        runs(rng, 1_000_000),
+        collision(rng, 200),
+    ]
+}
@@ -0,0 +1,292 @@
+//! Square matrix of bits (Galois(2)), with multiple words per column
+//!
+//! This is like [`BitMatrix`](crate::bitmatrix::BitMatrix), but each column is an array of
+//! `WORDS` unsigned integers rather than a single integer, so it can be wider than 128 bits. Eg
+//! for a 512×512 matrix, an array of 512 columns of `[u64; 8]` is used.
+//!
+//! A vector (eg a generator state) is an array of words in little-endian word order: bit `i` of
+//! the vector is bit `i % B` of word `i / B`, where B is the bit width of `T`. Bits of the last
+//! word beyond `WIDTH` must be zero.
+//!
+//! The columns are stored on the heap, so this module needs the `alloc` feature, and only single
+//! vectors go on the stack. A 512×512 matrix is 32 KiB, and a 19937×19937 matrix (Mersenne
+//! Twister) is about 48 MiB. `dot_vec()` takes O(WIDTH²) time, but multiplying matrices takes
+//! O(WIDTH³), which is minutes rather than milliseconds at 19937 bits.
+
+use crate::bitmatrix::BitMatrixInt;
+use crate::math;
+use alloc::vec::Vec;
+use num_traits::{ConstOne, ConstZero, One, Pow, PrimInt, Unsigned, Zero};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WideBitMatrix<T, const WORDS: usize, const WIDTH: usize>
+where
+    T: BitMatrixInt,
+{
+    /// `WIDTH` columns
+    columns: Vec<[T; WORDS]>,
+}
+
+/// Get bit `i` of a multi-word vector.
+fn get_bit<T: BitMatrixInt, const WORDS: usize>(v: &[T; WORDS], i: usize) -> bool {
+    let bits = math::size_of_bits::<T>();
+    (v[i / bits] >> (i % bits)) & T::ONE != T::ZERO
+}
+
+/// Toggle bit `i` of a multi-word vector.
+fn flip_bit<T: BitMatrixInt, const WORDS: usize>(v: &mut [T; WORDS], i: usize) {
+    let bits = math::size_of_bits::<T>();
+    v[i / bits] ^= T::ONE << (i % bits);
+}
+
+/// Exclusive-or multi-word vector `b` into `a`.
+fn xor_vec<T: BitMatrixInt, const WORDS: usize>(a: &mut [T; WORDS], b: &[T; WORDS]) {
+    for (a_word, &b_word) in a.iter_mut().zip(b.iter()) {
+        *a_word ^= b_word;
+    }
+}
+
+/// Position of the lowest set bit of a multi-word vector, at or above bit `start`.
+fn lowest_bit_from<T: BitMatrixInt, const WORDS: usize>(
+    v: &[T; WORDS],
+    start: usize,
+) -> Option<usize> {
+    let bits = math::size_of_bits::<T>();
+    let mut word_index = start / bits;
+    let mut word = v[word_index] & !math::bit_width_mask::<T>(start % bits);
+    loop {
+        if word != T::ZERO {
+            return Some(word_index * bits + word.trailing_zeros() as usize);
+        }
+        word_index += 1;
+        if word_index >= WORDS {
+            return None;
+        }
+        word = v[word_index];
+    }
+}
+
+impl<T, const WORDS: usize, const WIDTH: usize> WideBitMatrix<T, WORDS, WIDTH>
+where
+    T: BitMatrixInt,
+{
+    /// Whether `WORDS` words of `T` hold `WIDTH` bits, checked by every constructor
+    const WORDS_FIT: bool = WIDTH <= WORDS * math::size_of_bits::<T>();
+
+    pub fn new(init_data: &[[T; WORDS]; WIDTH]) -> WideBitMatrix<T, WORDS, WIDTH> {
+        assert!(Self::WORDS_FIT, "WORDS is too few for WIDTH");
+        WideBitMatrix::<T, WORDS, WIDTH> {
+            columns: init_data.to_vec(),
+        }
+    }
+
+    /// Create a matrix with column `i` given by `f(i)`, without building it on the stack first.
+    ///
+    /// Column `i` is the image of unit vector `i`, eg one step of a generator from a state with
+    /// only bit `i` set.
+    pub fn from_fn<F>(f: F) -> WideBitMatrix<T, WORDS, WIDTH>
+    where
+        F: FnMut(usize) -> [T; WORDS],
+    {
+        assert!(Self::WORDS_FIT, "WORDS is too few for WIDTH");
+        WideBitMatrix::<T, WORDS, WIDTH> {
+            columns: (0..WIDTH).map(f).collect(),
+        }
+    }
+
+    pub fn dot_vec(&self, b: &[T; WORDS]) -> [T; WORDS] {
+        let mut result = [T::ZERO; WORDS];
+        let bits = math::size_of_bits::<T>();
+        for (columns, &b_word) in self.columns.chunks(bits).zip(b.iter()) {
+            let mut b_temp = b_word;
+            for column in columns {
+                if b_temp & T::ONE != T::ZERO {
+                    xor_vec(&mut result, column);
+                }
+                b_temp = b_temp >> 1;
+            }
+        }
+        result
+    }
+
+    pub fn dot(&self, b: &WideBitMatrix<T, WORDS, WIDTH>) -> WideBitMatrix<T, WORDS, WIDTH> {
+        let mut result = WideBitMatrix::<T, WORDS, WIDTH>::zero();
+        for (result_column, b_column) in result.columns.iter_mut().zip(b.columns.iter()) {
+            *result_column = self.dot_vec(b_column);
+        }
+        result
+    }
+
+    pub fn dot_equ(&mut self, b: &WideBitMatrix<T, WORDS, WIDTH>) {
+        *self = self.dot(b);
+    }
+
+    /// Inverse of the matrix, or `None` if the matrix is singular.
+    ///
+    /// Gauss-Jordan elimination, in the same way as `BitMatrix::inverse()`.
+    pub fn inverse(&self) -> Option<WideBitMatrix<T, WORDS, WIDTH>> {
+        let mut reduced = self.clone();
+        let mut result = WideBitMatrix::<T, WORDS, WIDTH>::one();
+        for c in 0..WIDTH {
+            // Find a pivot row, at or below row c, with a 1 in column c.
+            let pivot = lowest_bit_from(&reduced.columns[c], c)?;
+            if pivot != c {
+                for matrix in [&mut reduced, &mut result].iter_mut() {
+                    for column in matrix.columns.iter_mut() {
+                        if get_bit(column, c) != get_bit(column, pivot) {
+                            flip_bit(column, c);
+                            flip_bit(column, pivot);
+                        }
+                    }
+                }
+            }
+
+            // Clear column c in all other rows, by adding the pivot row to them.
+            let mut clear = reduced.columns[c];
+            flip_bit(&mut clear, c);
+            for matrix in [&mut reduced, &mut result].iter_mut() {
+                for column in matrix.columns.iter_mut() {
+                    if get_bit(column, c) {
+                        xor_vec(column, &clear);
+                    }
+                }
+            }
+        }
+        Some(result)
+    }
+
+    /// Raise a matrix to a power, which may be negative.
+    ///
+    /// Returns `None` if the exponent is negative and the matrix is singular.
+    pub fn checked_pow<N>(&self, n: N) -> Option<WideBitMatrix<T, WORDS, WIDTH>>
+    where
+        N: math::IntTypes,
+    {
+        let base = if n < N::ZERO {
+            self.inverse()?
+        } else {
+            self.clone()
+        };
+        Some(base.pow_unsigned(N::abs_as_unsigned(n)))
+    }
+
+    /// Raise a matrix to a non-negative power. Efficient matrix exponentiation.
+    fn pow_unsigned<N>(self, n: N) -> WideBitMatrix<T, WORDS, WIDTH>
+    where
+        N: Unsigned + PrimInt + ConstOne + ConstZero,
+    {
+        let mut result = WideBitMatrix::<T, WORDS, WIDTH>::one();
+        let mut temp_exp = self;
+        let mut n_work: N = n;
+
+        loop {
+            if n_work & N::ONE != N::ZERO {
+                result.dot_equ(&temp_exp);
+            }
+            n_work = n_work >> 1;
+            if n_work == N::ZERO {
+                break;
+            }
+            let temp_exp2 = temp_exp.clone();
+            temp_exp.dot_equ(&temp_exp2);
+        }
+        result
+    }
+}
+
+impl<T, const WORDS: usize, const WIDTH: usize> Zero for WideBitMatrix<T, WORDS, WIDTH>
+where
+    T: BitMatrixInt,
+{
+    /// Create a zero-matrix.
+    fn zero() -> WideBitMatrix<T, WORDS, WIDTH> {
+        assert!(Self::WORDS_FIT, "WORDS is too few for WIDTH");
+        WideBitMatrix::<T, WORDS, WIDTH> {
+            columns: alloc::vec![[T::ZERO; WORDS]; WIDTH],
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.columns
+            .iter()
+            .all(|column| column.iter().all(|&word| word == T::ZERO))
+    }
+}
+
+impl<T, const WORDS: usize, const WIDTH: usize> One for WideBitMatrix<T, WORDS, WIDTH>
+where
+    T: BitMatrixInt,
+{
+    /// Create a unity-matrix. That is, ones on the diagonal, zeros elsewhere.
+    fn one() -> WideBitMatrix<T, WORDS, WIDTH> {
+        let mut result = WideBitMatrix::<T, WORDS, WIDTH>::zero();
+        for i in 0..WIDTH {
+            flip_bit(&mut result.columns[i], i);
+        }
+        result
+    }
+}
+
+impl<N, T, const WORDS: usize, const WIDTH: usize> Pow<N> for WideBitMatrix<T, WORDS, WIDTH>
+where
+    T: BitMatrixInt,
+    N: math::IntTypes,
+{
+    type Output = Self;
+
+    /// Raise a matrix to a power. Efficient matrix exponentiation.
+    ///
+    /// A negative power raises the inverse of the matrix. See `checked_pow()` for a version that
+    /// doesn't panic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the power is negative and the matrix is singular.
+    fn pow(self, n: N) -> WideBitMatrix<T, WORDS, WIDTH> {
+        self.checked_pow(n)
+            .expect("negative power of a singular matrix")
+    }
+}
+
+impl<T, const WORDS: usize, const WIDTH: usize> core::ops::Add for WideBitMatrix<T, WORDS, WIDTH>
+where
+    T: BitMatrixInt,
+{
+    type Output = Self;
+
+    /// Add two matrices.
+    /// Addition in Galois(2) is exclusive-or.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, b: WideBitMatrix<T, WORDS, WIDTH>) -> WideBitMatrix<T, WORDS, WIDTH> {
+        let mut result = self;
+        for (column, b_column) in result.columns.iter_mut().zip(b.columns.iter()) {
+            xor_vec(column, b_column);
+        }
+        result
+    }
+}
+
+impl<T, const WORDS: usize, const WIDTH: usize> core::ops::Mul for WideBitMatrix<T, WORDS, WIDTH>
+where
+    T: BitMatrixInt,
+{
+    type Output = Self;
+
+    /// Multiply two matrices.
+    fn mul(self, b: WideBitMatrix<T, WORDS, WIDTH>) -> WideBitMatrix<T, WORDS, WIDTH> {
+        self.dot(&b)
+    }
+}
+
+impl<'b, T, const WORDS: usize, const WIDTH: usize>
+    core::ops::Mul<&'b WideBitMatrix<T, WORDS, WIDTH>> for &WideBitMatrix<T, WORDS, WIDTH>
+where
+    T: BitMatrixInt,
+{
+    type Output = WideBitMatrix<T, WORDS, WIDTH>;
+
+    /// Multiply two matrices (by reference).
+    fn mul(self, b: &'b WideBitMatrix<T, WORDS, WIDTH>) -> WideBitMatrix<T, WORDS, WIDTH> {
+        self.dot(b)
+    }
+}
@@ -0,0 +1,97 @@
+use rand_core::RngCore;
+use ssrand::anyrng::{self, AnyRng, ParseRngError, GENERATORS};
+use ssrand::RngJumpAhead;
+
+#[test]
+fn test_new_by_name() {
+    let seeds = [2247183469, 99545079, 3269400377, 3950144837];
+    for info in GENERATORS.iter() {
+        let rng = AnyRng::new(info.name, &seeds[..info.seed_count]).unwrap();
+        assert_eq!(rng.name(), info.name);
+        assert_eq!(rng.info(), info);
+        let lower = AnyRng::new(&info.name.to_ascii_lowercase(), &seeds[..info.seed_count]);
+        assert_eq!(lower, Ok(rng));
+    }
+
+    assert_eq!(
+        AnyRng::new("kiss2", &seeds),
+        Ok(AnyRng::KISS2(ssrand::KISS2::new(
+            2247183469, 99545079, 3269400377, 3950144837
+        )))
+    );
+    assert_eq!(
+        AnyRng::new("LFSR88", &seeds[..3]),
+        Ok(ssrand::LFSR88::new(2247183469, 99545079, 3269400377).into())
+    );
+    assert_eq!(
+        AnyRng::new("KISS3", &seeds),
+        Err(ParseRngError::UnknownName)
+    );
+    assert_eq!(AnyRng::new("", &[]), Err(ParseRngError::UnknownName));
+    assert_eq!(
+        AnyRng::new("MWC64", &seeds[..3]),
+        Err(ParseRngError::SeedCount {
+            expected: 2,
+            given: 3
+        })
+    );
+    assert_eq!(anyrng::generator_info("lfsr113").unwrap().seed_count, 4);
+    assert_eq!(anyrng::generator_info("LFSR"), None);
+}
+
+#[test]
+fn test_dispatch() {
+    let mut rng = AnyRng::new("KISS", &[2247183469, 99545079, 3269400377, 3950144837]).unwrap();
+    let mut kiss = ssrand::KISS::new(2247183469, 99545079, 3269400377, 3950144837);
+    for _ in 0..10 {
+        assert_eq!(rng.next_u32(), kiss.next_u32());
+    }
+    assert_eq!(rng.next_u64(), kiss.next_u64());
+    let mut bytes = [0_u8; 13];
+    let mut kiss_bytes = [0_u8; 13];
+    rng.fill_bytes(&mut bytes);
+    kiss.fill_bytes(&mut kiss_bytes);
+    assert_eq!(bytes, kiss_bytes);
+
+    rng.jumpahead(-1_000_000_i64);
+    kiss.jumpahead(-1_000_000_i64);
+    rng.jumpahead_pow2(100);
+    kiss.jumpahead_pow2(100);
+    assert_eq!(rng, AnyRng::KISS(kiss.clone()));
+    assert_eq!(format!("{:?}", rng), format!("{:?}", kiss));
+}
+
+#[test]
+fn test_periods() {
+    assert_eq!(ssrand::Cong::PERIOD, 1 << 32);
+    assert_eq!(ssrand::SHR3::PERIOD, (1 << 32) - 1);
+    assert_eq!(ssrand::MWC2::PERIOD, 1211400191 * 589823999);
+    assert_eq!(ssrand::MWC1::PERIOD, ssrand::MWC2::PERIOD);
+    assert_eq!(ssrand::MWC64::PERIOD, (698769069 << 32) / 2 - 1);
+    assert_eq!(
+        ssrand::LFSR88::PERIOD,
+        ((1 << 31) - 1) * ((1 << 29) - 1) * ((1 << 28) - 1)
+    );
+    assert_eq!(
+        ssrand::LFSR113::PERIOD,
+        ((1 << 31) - 1) * ((1 << 29) - 1) * ((1 << 28) - 1) * ((1 << 25) - 1)
+    );
+    // The component periods are coprime.
+    assert_eq!(
+        ssrand::KISS::PERIOD,
+        ssrand::MWC2::PERIOD * (1 << 32) * ((1 << 32) - 1)
+    );
+    assert_eq!(
+        ssrand::KISS2::PERIOD,
+        ssrand::MWC64::PERIOD * (1 << 32) * ((1 << 32) - 1)
+    );
+
+    // Jumping by the period changes nothing.
+    let seeds = [1, 2, 3, 4];
+    for info in GENERATORS.iter() {
+        let rng = AnyRng::new(info.name, &seeds[..info.seed_count]).unwrap();
+        let mut jumped = rng.clone();
+        jumped.jumpahead(info.period);
+        assert_eq!(jumped, rng, "{}", info.name);
+    }
+}
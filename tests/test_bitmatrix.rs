@@ -11,7 +11,7 @@ fn test_zero() {
     while x != 0 {
         let mul_result = zero.dot_vec(x);
         assert_eq!(mul_result, 0);
-        x = x >> 1;
+        x >>= 1;
     }
 }
 
@@ -22,7 +22,7 @@ fn test_one() {
     while x != 0 {
         let mul_result = one.dot_vec(x);
         assert_eq!(mul_result, x);
-        x = x >> 1;
+        x >>= 1;
     }
 }
 
@@ -83,7 +83,7 @@ fn test_shift() {
                 let shift_right_by = -shift_by;
                 assert_eq!(mul_result, x >> shift_right_by);
             }
-            x = x >> 1;
+            x >>= 1;
         }
     }
 }
@@ -100,7 +100,7 @@ fn test_bitand() {
     while x != 0 {
         let mul_result = mask_matrix.dot_vec(x);
         assert_eq!(mul_result, x & mask);
-        x = x >> 1;
+        x >>= 1;
     }
 }
 
@@ -168,3 +168,287 @@ fn test_lfsr113_1_matrix() {
 
     assert_eq!(lfsr88_z1_matrix, built_lfsr88_z1_matrix);
 }
+
+#[test]
+fn test_rank() {
+    assert_eq!(BitMatrix32::zero().rank(), 0);
+    assert_eq!(BitMatrix32::one().rank(), 32);
+    assert_eq!(BitMatrix32::shift(13).rank(), 19);
+    assert_eq!(BitMatrix32::shift(-17).rank(), 15);
+    assert_eq!((BitMatrix32::one() & 0xA5F01248).rank(), 12);
+
+    // Rank of a 31×31 matrix, with rows and columns not the full width of u32.
+    assert_eq!(BitMatrix::<u32, 31>::one().rank(), 31);
+    assert_eq!(BitMatrix::<u32, 31>::shift(1).rank(), 30);
+
+    // Duplicate columns don't add to the rank.
+    let mut columns = [0_u32; 32];
+    for (i, column) in columns.iter_mut().enumerate() {
+        *column = 0x9E3779B9_u32.rotate_left((i % 5) as u32);
+    }
+    assert_eq!(BitMatrix32::new(&columns).rank(), 5);
+}
+
+#[test]
+fn test_row_reduce() {
+    const SHR3_MATRIX_ARRAY: [u32; 32] = [
+        0x00042021, 0x00084042, 0x00108084, 0x00210108, 0x00420231, 0x00840462, 0x010808C4,
+        0x02101188, 0x04202310, 0x08404620, 0x10808C40, 0x21011880, 0x42023100, 0x84046200,
+        0x0808C400, 0x10118800, 0x20231000, 0x40462021, 0x808C4042, 0x01080084, 0x02100108,
+        0x04200210, 0x08400420, 0x10800840, 0x21001080, 0x42002100, 0x84004200, 0x08008400,
+        0x10010800, 0x20021000, 0x40042000, 0x80084000,
+    ];
+    let shr3_matrix = BitMatrix32::new(&SHR3_MATRIX_ARRAY);
+    let (reduced, rank) = shr3_matrix.row_reduce();
+    assert_eq!(rank, 32);
+    assert_eq!(reduced, BitMatrix32::one());
+    assert_eq!(shr3_matrix.determinant(), 1);
+
+    // The LFSR88 z1 matrix ignores bit 0 of its input, so its first column is zero.
+    const LFSR88_Z1_MATRIX_ARRAY: [u32; 32] = [
+        0x00000000, 0x00002000, 0x00004000, 0x00008000, 0x00010000, 0x00020000, 0x00040001,
+        0x00080002, 0x00100004, 0x00200008, 0x00400010, 0x00800020, 0x01000040, 0x02000080,
+        0x04000100, 0x08000200, 0x10000400, 0x20000800, 0x40001000, 0x80000001, 0x00000002,
+        0x00000004, 0x00000008, 0x00000010, 0x00000020, 0x00000040, 0x00000080, 0x00000100,
+        0x00000200, 0x00000400, 0x00000800, 0x00001000,
+    ];
+    let lfsr88_z1_matrix = BitMatrix32::new(&LFSR88_Z1_MATRIX_ARRAY);
+    let (reduced, rank) = lfsr88_z1_matrix.row_reduce();
+    assert_eq!(rank, 31);
+    assert_eq!(reduced, BitMatrix32::shift(-1));
+    assert_eq!(lfsr88_z1_matrix.determinant(), 0);
+
+    // A small example, worked by hand.
+    let m = BitMatrix::<u8, 4>::new(&[0b0110, 0b1100, 0b1010, 0b0001]);
+    let (reduced, rank) = m.row_reduce();
+    assert_eq!(rank, 3);
+    assert_eq!(
+        reduced,
+        BitMatrix::<u8, 4>::new(&[0b0001, 0b0010, 0b0011, 0b0100])
+    );
+}
+
+#[test]
+fn test_transpose() {
+    assert_eq!(BitMatrix32::one().transpose(), BitMatrix32::one());
+    assert_eq!(BitMatrix32::shift(7).transpose(), BitMatrix32::shift(-7));
+    assert_eq!(
+        BitMatrix::<u8, 4>::new(&[0b0110, 0b1100, 0b1010, 0b0001]).transpose(),
+        BitMatrix::<u8, 4>::new(&[0b1000, 0b0101, 0b0011, 0b0110])
+    );
+
+    // (AB)ᵀ = BᵀAᵀ
+    let a = BitMatrix32::one() + (BitMatrix32::one() << 13);
+    let b = BitMatrix32::one() + (BitMatrix32::one() >> 17);
+    assert_eq!((&a * &b).transpose(), b.transpose() * a.transpose());
+    assert_eq!(a.transpose().transpose(), a);
+}
+
+#[test]
+fn test_inverse() {
+    const SHR3_MATRIX_ARRAY: [u32; 32] = [
+        0x00042021, 0x00084042, 0x00108084, 0x00210108, 0x00420231, 0x00840462, 0x010808C4,
+        0x02101188, 0x04202310, 0x08404620, 0x10808C40, 0x21011880, 0x42023100, 0x84046200,
+        0x0808C400, 0x10118800, 0x20231000, 0x40462021, 0x808C4042, 0x01080084, 0x02100108,
+        0x04200210, 0x08400420, 0x10800840, 0x21001080, 0x42002100, 0x84004200, 0x08008400,
+        0x10010800, 0x20021000, 0x40042000, 0x80084000,
+    ];
+    let shr3_matrix = BitMatrix32::new(&SHR3_MATRIX_ARRAY);
+    let shr3_inverse = shr3_matrix.inverse().unwrap();
+    assert_eq!(&shr3_matrix * &shr3_inverse, BitMatrix32::one());
+    assert_eq!(&shr3_inverse * &shr3_matrix, BitMatrix32::one());
+    // Stepping SHR3 backwards.
+    assert_eq!(
+        shr3_inverse.dot_vec(shr3_matrix.dot_vec(3360276411)),
+        3360276411
+    );
+
+    // The inverse of (I + L^13) is I + L^13 + L^26.
+    let a = BitMatrix32::one() + (BitMatrix32::one() << 13);
+    let a_inverse = a.clone() + (BitMatrix32::one() << 13) * (BitMatrix32::one() << 13);
+    assert_eq!(a.inverse(), Some(a_inverse));
+
+    assert_eq!(BitMatrix32::one().inverse(), Some(BitMatrix32::one()));
+    assert_eq!(BitMatrix32::zero().inverse(), None);
+    assert_eq!(BitMatrix32::shift(1).inverse(), None);
+}
+
+#[test]
+fn test_negative_pow() {
+    const SHR3_MATRIX_ARRAY: [u32; 32] = [
+        0x00042021, 0x00084042, 0x00108084, 0x00210108, 0x00420231, 0x00840462, 0x010808C4,
+        0x02101188, 0x04202310, 0x08404620, 0x10808C40, 0x21011880, 0x42023100, 0x84046200,
+        0x0808C400, 0x10118800, 0x20231000, 0x40462021, 0x808C4042, 0x01080084, 0x02100108,
+        0x04200210, 0x08400420, 0x10800840, 0x21001080, 0x42002100, 0x84004200, 0x08008400,
+        0x10010800, 0x20021000, 0x40042000, 0x80084000,
+    ];
+    let shr3_matrix = BitMatrix32::new(&SHR3_MATRIX_ARRAY);
+
+    // SHR3 has period 2^32-1, so a negative power is the same as the complementary power.
+    assert_eq!(
+        shr3_matrix.clone().pow(-1_000_000_000_i64),
+        shr3_matrix.clone().pow(4_294_967_295_u32 - 1_000_000_000)
+    );
+    assert_eq!(
+        shr3_matrix.clone().pow(-5_i8) * shr3_matrix.clone().pow(5_u8),
+        BitMatrix32::one()
+    );
+    assert_eq!(shr3_matrix.clone().pow(0_i32), BitMatrix32::one());
+    assert_eq!(
+        shr3_matrix.clone().pow(-1_i32),
+        shr3_matrix.inverse().unwrap()
+    );
+
+    assert_eq!(
+        shr3_matrix.checked_pow(-1_i32),
+        Some(shr3_matrix.inverse().unwrap())
+    );
+    assert_eq!(BitMatrix32::shift(3).checked_pow(-1_i32), None);
+    assert_eq!(
+        BitMatrix32::shift(3).checked_pow(11_i32),
+        Some(BitMatrix32::zero())
+    );
+}
+
+#[test]
+fn test_neg() {
+    let a = BitMatrix32::one() + (BitMatrix32::one() << 13);
+    assert_eq!(-a.clone(), a);
+    assert_eq!(a.clone() + -a, BitMatrix32::zero());
+}
+
+#[test]
+fn test_minimal_polynomial() {
+    assert_eq!(BitMatrix32::one().minimal_polynomial(), 0b11);
+    assert_eq!(BitMatrix32::zero().minimal_polynomial(), 0b10);
+    // Shifting is nilpotent.
+    assert_eq!(BitMatrix32::shift(1).minimal_polynomial(), 1 << 32);
+    assert_eq!(BitMatrix32::shift(-5).minimal_polynomial(), 1 << 7);
+    // Companion matrix of x^4 + x + 1.
+    let companion = BitMatrix::<u8, 4>::new(&[0b0010, 0b0100, 0b1000, 0b0011]);
+    assert_eq!(companion.minimal_polynomial(), 0b10011);
+    assert!(companion.verify_period(15));
+    assert!(!companion.verify_period(5));
+    assert!(!companion.verify_period(30));
+}
+
+#[test]
+fn test_shr3_period() {
+    use ssrand::gf2poly;
+
+    let left = |n| BitMatrix32::one() + (BitMatrix32::one() << n);
+    let right = |n| BitMatrix32::one() + (BitMatrix32::one() >> n);
+
+    // The SHR3 of [mars2] has period 2^32-1.
+    let shr3_matrix = left(5) * right(17) * left(13);
+    let minimal_polynomial = shr3_matrix.minimal_polynomial();
+    assert_eq!(gf2poly::degree(minimal_polynomial), Some(32));
+    assert!(gf2poly::is_primitive(minimal_polynomial));
+    assert!(shr3_matrix.verify_period(0xFFFFFFFF));
+
+    // The SHR3 of [mars1], with two shifts swapped, doesn't. Greg Rose found it has 64 cycles.
+    let shr3_matrix = left(5) * right(13) * left(17);
+    let minimal_polynomial = shr3_matrix.minimal_polynomial();
+    assert!(!gf2poly::is_irreducible(minimal_polynomial));
+    assert!(!shr3_matrix.verify_period(0xFFFFFFFF));
+}
+
+#[test]
+fn test_dot_table() {
+    // Pseudo-random matrices, of various widths.
+    let mut x = 0x9E3779B97F4A7C15_u64;
+    let mut next = || {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        x
+    };
+
+    let mut columns_a = [0_u32; 32];
+    let mut columns_b = [0_u32; 32];
+    for i in 0..32 {
+        columns_a[i] = next() as u32;
+        columns_b[i] = next() as u32;
+    }
+    let a = BitMatrix32::new(&columns_a);
+    let b = BitMatrix32::new(&columns_b);
+    assert_eq!(a.dot_table(&b), a.dot(&b));
+    assert_eq!(b.dot_table(&a), b.dot(&a));
+
+    let mut columns_a = [0_u128; 128];
+    let mut columns_b = [0_u128; 128];
+    for i in 0..128 {
+        columns_a[i] = ((next() as u128) << 64) | next() as u128;
+        columns_b[i] = ((next() as u128) << 64) | next() as u128;
+    }
+    let a = BitMatrix::<u128, 128>::new(&columns_a);
+    let b = BitMatrix::<u128, 128>::new(&columns_b);
+    assert_eq!(a.dot_table(&b), a.dot(&b));
+
+    // Width not a multiple of 4.
+    let mut columns_a = [0_u8; 7];
+    let mut columns_b = [0_u8; 7];
+    for i in 0..7 {
+        columns_a[i] = next() as u8 & 0x7F;
+        columns_b[i] = next() as u8 & 0x7F;
+    }
+    let a = BitMatrix::<u8, 7>::new(&columns_a);
+    let b = BitMatrix::<u8, 7>::new(&columns_b);
+    assert_eq!(a.dot_table(&b), a.dot(&b));
+    assert_eq!(BitMatrix::<u8, 7>::one().dot_table(&b), b);
+
+    // The tables are sized for the width, here 13 and 40 columns, rather than the type.
+    let mut columns_a = [0_u16; 13];
+    let mut columns_b = [0_u16; 13];
+    for i in 0..13 {
+        columns_a[i] = next() as u16 & 0x1FFF;
+        columns_b[i] = next() as u16 & 0x1FFF;
+    }
+    let a = BitMatrix::<u16, 13>::new(&columns_a);
+    let b = BitMatrix::<u16, 13>::new(&columns_b);
+    assert_eq!(a.dot_table(&b), a.dot(&b));
+    let mut columns_a = [0_u64; 40];
+    let mut columns_b = [0_u64; 40];
+    for i in 0..40 {
+        columns_a[i] = next() & 0xFF_FFFF_FFFF;
+        columns_b[i] = next() & 0xFF_FFFF_FFFF;
+    }
+    let a = BitMatrix::<u64, 40>::new(&columns_a);
+    let b = BitMatrix::<u64, 40>::new(&columns_b);
+    assert_eq!(a.dot_table(&b), a.dot(&b));
+    assert_eq!(a.clone().pow(3_u32), a.dot(&a).dot(&a));
+}
+
+#[test]
+fn test_const_pow() {
+    // SHR3 matrix, as in test_shr3_matrix().
+    const SHR3_1: BitMatrix32 = {
+        let mut columns = [0_u32; 32];
+        let mut i = 0;
+        while i < 32 {
+            let mut x = 1_u32 << i;
+            x ^= x << 13;
+            x ^= x >> 17;
+            x ^= x << 5;
+            columns[i] = x;
+            i += 1;
+        }
+        BitMatrix32::new(&columns)
+    };
+    const SHR3_1000: BitMatrix32 = SHR3_1.const_pow(1000);
+    assert_eq!(SHR3_1000, SHR3_1.clone().pow(1000_u32));
+    assert_eq!(SHR3_1.const_pow(0), BitMatrix32::one());
+    assert_eq!(SHR3_1.const_dot(&SHR3_1), SHR3_1.dot(&SHR3_1));
+    assert_eq!(SHR3_1000.const_dot_vec(12345), SHR3_1000.dot_vec(12345));
+
+    let mut x = 0x9E3779B97F4A7C15_u64;
+    let mut columns = [0_u64; 64];
+    for column in columns.iter_mut() {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        *column = x;
+    }
+    let a = BitMatrix::<u64, 64>::new(&columns);
+    assert_eq!(a.const_pow(123456789), a.clone().pow(123456789_u64));
+}
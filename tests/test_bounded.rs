@@ -0,0 +1,83 @@
+use ssrand::bounded::RngBounded;
+use ssrand::{RngJumpAhead, KISS};
+
+/// KISS with the seeds of test_kiss_million(). Its first outputs are 2248896781, 848205061,
+/// 4043705583, 2839499325, 3887311424, 1352189383, 1951220864, 3555621892, 1421316489,
+/// 1018957591, ...
+fn kiss() -> KISS {
+    KISS::new(2247183469, 99545079, 3269400377, 3950144837)
+}
+
+#[test]
+fn test_next_below() {
+    // Values from a separate implementation of the documented algorithm.
+    let mut rng = kiss();
+    let values: Vec<u32> = (0..6).map(|_| rng.next_below(6)).collect();
+    assert_eq!(values, [3, 1, 5, 3, 5, 1]);
+    let mut rng = kiss();
+    let values: Vec<u32> = (0..6).map(|_| rng.next_below(1000)).collect();
+    assert_eq!(values, [523, 197, 941, 661, 905, 314]);
+
+    // Bounds of 1 and 2^32 - 1.
+    assert_eq!(kiss().next_below(1), 0);
+    assert_eq!(kiss().next_below(u32::MAX), 2248896780);
+}
+
+#[test]
+fn test_next_below_rejection() {
+    // For this bound, nearly half the attempts are rejected. Six values take ten outputs, so the
+    // generator ends up where a jump ahead of ten leaves it.
+    let mut rng = kiss();
+    let values: Vec<u32> = (0..6).map(|_| rng.next_below(0x80000001)).collect();
+    assert_eq!(
+        values,
+        [424102530, 1943655712, 676094691, 1777810946, 710658244, 509478795]
+    );
+    let mut rng_ja = kiss();
+    rng_ja.jumpahead(10);
+    assert_eq!(rng, rng_ja);
+}
+
+#[test]
+fn test_next_below_u64() {
+    let mut rng = kiss();
+    let values: Vec<u64> = (0..3)
+        .map(|_| rng.next_below_u64(1_000_000_000_000))
+        .collect();
+    assert_eq!(values, [197488130425, 661122455713, 314831124596]);
+
+    // Each word is two outputs. Two values here take four words, with two rejections.
+    let mut rng = kiss();
+    let values: Vec<u64> = (0..2).map(|_| rng.next_below_u64((1 << 63) + 1)).collect();
+    assert_eq!(values, [1821506499772790918, 7635639872516432448]);
+    let mut rng_ja = kiss();
+    rng_ja.jumpahead(8);
+    assert_eq!(rng, rng_ja);
+}
+
+#[test]
+fn test_range() {
+    let mut rng = kiss();
+    let values: Vec<i32> = (0..6).map(|_| rng.range(-2, 4)).collect();
+    assert_eq!(values, [1, -1, 3, 1, 3, -1]);
+
+    // The widest ranges don't overflow.
+    let mut rng = kiss();
+    for _ in 0..100 {
+        assert!(rng.range(i32::MIN, i32::MAX) < i32::MAX);
+        assert!(rng.range_i64(i64::MIN, i64::MAX) < i64::MAX);
+    }
+    assert_eq!(kiss().range_i64(-5, -4), -5);
+}
+
+#[test]
+#[should_panic(expected = "bound must be non-zero")]
+fn test_next_below_zero() {
+    kiss().next_below(0);
+}
+
+#[test]
+#[should_panic(expected = "empty range")]
+fn test_range_empty() {
+    kiss().range(3, 3);
+}
@@ -0,0 +1,323 @@
+use std::process::Command;
+
+fn ssrand_bytes(args: &[&str]) -> (bool, Vec<u8>) {
+    let output = Command::new(env!("CARGO_BIN_EXE_ssrand"))
+        .args(args)
+        .output()
+        .expect("failed to run ssrand");
+    (output.status.success(), output.stdout)
+}
+
+fn ssrand(args: &[&str]) -> (bool, String) {
+    let (ok, stdout) = ssrand_bytes(args);
+    (ok, String::from_utf8(stdout).unwrap())
+}
+
+/// Get the first `n` outputs of LFSR113 with seeds 1, 2, 3, 4 via the generate command.
+fn lfsr113_outputs(n: usize) -> Vec<u32> {
+    let (ok, out) = ssrand(&[
+        "generate",
+        "LFSR113",
+        "1",
+        "2",
+        "3",
+        "4",
+        "-n",
+        &n.to_string(),
+    ]);
+    assert!(ok);
+    out.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+#[test]
+fn test_generate_jumpahead() {
+    // Same reference value as test_kiss_million() in test_lib.rs.
+    let (ok, out) = ssrand(&[
+        "generate",
+        "kiss",
+        "2247183469",
+        "99545079",
+        "3269400377",
+        "3950144837",
+        "-j",
+        "999999",
+        "-n",
+        "1",
+    ]);
+    assert!(ok);
+    assert_eq!(out, "2100752872\n");
+}
+
+#[test]
+fn test_generate_big_jumpahead() {
+    // 2^200 - 1 is -1 modulo the period 2^32, so this gives back the seed.
+    let jump = format!("0x{}", "F".repeat(50));
+    let (ok, out) = ssrand(&[
+        "generate",
+        "Cong",
+        "0x7A3B12C4",
+        "-j",
+        &jump,
+        "-n",
+        "1",
+        "-f",
+        "hex",
+    ]);
+    assert!(ok);
+    assert_eq!(out, "0x7A3B12C4\n");
+
+    // The same in decimal.
+    let (ok, out_dec) = ssrand(&[
+        "generate",
+        "Cong",
+        "0x7A3B12C4",
+        "-j",
+        "1606938044258990275541962092341162602522202993782792835301375",
+        "-n",
+        "1",
+        "-f",
+        "hex",
+    ]);
+    assert!(ok);
+    assert_eq!(out_dec, out);
+
+    // Beyond 2^256, or not a number.
+    let jump = format!("0x1{}", "0".repeat(64));
+    let (ok, _) = ssrand(&["generate", "Cong", "1", "-j", &jump]);
+    assert!(!ok);
+    let (ok, _) = ssrand(&["generate", "Cong", "1", "-j", "12z"]);
+    assert!(!ok);
+}
+
+#[test]
+fn test_generate_negative_jumpahead() {
+    // Jumping back one step, then generating one value, gives back the seed.
+    let (ok, out) = ssrand(&[
+        "generate",
+        "Cong",
+        "0x7A3B12C4",
+        "-j",
+        "-1",
+        "-n",
+        "1",
+        "-f",
+        "hex",
+    ]);
+    assert!(ok);
+    assert_eq!(out, "0x7A3B12C4\n");
+
+    // Jumping a huge amount works via modulo of the period.
+    let (ok, out) = ssrand(&[
+        "generate",
+        "SHR3",
+        "3360276411",
+        "-j",
+        "-170141183460469231731687303715884105728",
+        "-n",
+        "1",
+    ]);
+    let (_, out_ref) = ssrand(&[
+        "generate",
+        "SHR3",
+        "3360276411",
+        "-j",
+        "2147483647", // -2^127 modulo the SHR3 period 2^32-1
+        "-n",
+        "1",
+    ]);
+    assert!(ok);
+    assert_eq!(out, out_ref);
+}
+
+#[test]
+fn test_generate_state() {
+    let (ok, out) = ssrand(&["generate", "KISS", "1", "2", "3", "4", "-n", "0"]);
+    assert!(ok);
+    assert_eq!(out, "");
+
+    let (ok, out) = ssrand(&["generate", "MWC1", "0", "0", "-n", "1", "-s", "json"]);
+    assert!(ok);
+    let json = out.split_once(' ').unwrap().1.trim_end();
+    assert!(json.starts_with("{\"type\":\"MWC1\",\"mwc\":{\"type\":\"MWC2\",\"upper\":"));
+    assert!(json.ends_with("}}"));
+}
+
+#[test]
+fn test_generate_float() {
+    // Same reference values as test_lfsr113_c() in test_float.rs.
+    let lfsr113_c = [
+        "LFSR113",
+        "0x526F68B1",
+        "0x526F68B1",
+        "0x526F68B1",
+        "0x526F68B1",
+    ];
+    let mut args = vec!["generate"];
+    args.extend_from_slice(&lfsr113_c);
+    args.extend_from_slice(&["-n", "2", "-f", "float"]);
+    let (ok, out) = ssrand(&args);
+    assert!(ok);
+    assert_eq!(out, "0.92027792800217867\n0.27776457089930773\n");
+
+    // float53 takes two outputs for each value.
+    let mut args = vec!["generate"];
+    args.extend_from_slice(&lfsr113_c);
+    args.extend_from_slice(&["-n", "1", "-f", "float53"]);
+    let (ok, out) = ssrand(&args);
+    assert!(ok);
+    assert_eq!(out, "0.92027792541507303\n");
+}
+
+#[test]
+fn test_generate_below() {
+    // Same values as test_next_below() in test_bounded.rs.
+    let kiss = ["KISS", "2247183469", "99545079", "3269400377", "3950144837"];
+    let mut args = vec!["generate"];
+    args.extend_from_slice(&kiss);
+    args.extend_from_slice(&["-n", "6", "--below", "6"]);
+    let (ok, out) = ssrand(&args);
+    assert!(ok);
+    assert_eq!(out, "3\n1\n5\n3\n5\n1\n");
+    let mut args = vec!["generate"];
+    args.extend_from_slice(&kiss);
+    args.extend_from_slice(&["-n", "2", "-f", "hex", "--below", "1000"]);
+    let (ok, out) = ssrand(&args);
+    assert!(ok);
+    assert_eq!(out, "0x0000020B\n0x000000C5\n");
+
+    let (ok, _) = ssrand(&["generate", "Cong", "1", "-B", "0"]);
+    assert!(!ok);
+    // Bounded integers have no float format, in either order of the options.
+    let (ok, _) = ssrand(&["generate", "Cong", "1", "-B", "6", "-f", "float"]);
+    assert!(!ok);
+    let (ok, _) = ssrand(&["generate", "Cong", "1", "-f", "float53", "-B", "6"]);
+    assert!(!ok);
+}
+
+#[test]
+fn test_generate_errors() {
+    let (ok, _) = ssrand(&["generate", "KISS", "1", "2"]);
+    assert!(!ok);
+    let (ok, _) = ssrand(&["generate", "NOSUCH", "1"]);
+    assert!(!ok);
+    let (ok, _) = ssrand(&[]);
+    assert!(!ok);
+}
+
+#[test]
+fn test_generate_expand() {
+    // Same seeds as test_seed_sequence() in test_seed.rs.
+    let (ok, out) = ssrand(&["generate", "KISS", "-x", "42"]);
+    assert!(ok);
+    let (_, out_ref) = ssrand(&[
+        "generate",
+        "KISS",
+        "0x9EC7A148",
+        "0xC97B7891",
+        "0x75AB4B47",
+        "0x09205B33",
+    ]);
+    assert_eq!(out, out_ref);
+
+    let (ok, _) = ssrand(&["generate", "KISS", "1", "--expand", "42"]);
+    assert!(!ok);
+}
+
+#[test]
+fn test_stream_endianness() {
+    let outputs = lfsr113_outputs(4);
+
+    let (ok, out) = ssrand_bytes(&["stream", "LFSR113", "1", "2", "3", "4", "-b", "16"]);
+    assert!(ok);
+    let expected: Vec<u8> = outputs.iter().flat_map(|x| x.to_le_bytes()).collect();
+    assert_eq!(out, expected);
+
+    let (ok, out) = ssrand_bytes(&[
+        "stream", "LFSR113", "1", "2", "3", "4", "-b", "16", "-e", "big",
+    ]);
+    assert!(ok);
+    let expected: Vec<u8> = outputs.iter().flat_map(|x| x.to_be_bytes()).collect();
+    assert_eq!(out, expected);
+
+    // 64-bit words are two outputs, first in the low half.
+    let (ok, out) = ssrand_bytes(&[
+        "stream", "LFSR113", "1", "2", "3", "4", "-b", "16", "-e", "big", "-w", "64",
+    ]);
+    assert!(ok);
+    let expected: Vec<u8> = outputs
+        .chunks(2)
+        .flat_map(|x| (((x[1] as u64) << 32) | x[0] as u64).to_be_bytes())
+        .collect();
+    assert_eq!(out, expected);
+
+    // 16-bit words are halves of an output, low half first.
+    let (ok, out) = ssrand_bytes(&[
+        "stream", "LFSR113", "1", "2", "3", "4", "-b", "4", "-e", "big", "-w", "16",
+    ]);
+    assert!(ok);
+    let expected: Vec<u8> = [outputs[0] as u16, (outputs[0] >> 16) as u16]
+        .iter()
+        .flat_map(|x| x.to_be_bytes())
+        .collect();
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_stream_interleave() {
+    // Three substreams, each jumped one step from the previous one.
+    let outputs = lfsr113_outputs(6);
+    let (ok, out) = ssrand_bytes(&[
+        "stream", "LFSR113", "1", "2", "3", "4", "-b", "24", "-i", "3", "-t", "1",
+    ]);
+    assert!(ok);
+    let expected: Vec<u8> = [0, 1, 2, 1, 2, 3]
+        .iter()
+        .flat_map(|&i| outputs[i].to_le_bytes())
+        .collect();
+    assert_eq!(out, expected);
+
+    // A large length spans several buffer fills.
+    let (ok, out) = ssrand_bytes(&[
+        "stream", "KISS", "1", "2", "3", "4", "-b", "100003", "-i", "2", "-t", "-5",
+    ]);
+    assert!(ok);
+    assert_eq!(out.len(), 100003);
+
+    let (ok, _) = ssrand_bytes(&["stream", "KISS", "1", "2", "3", "4", "-i", "2"]);
+    assert!(!ok);
+}
+
+#[test]
+fn test_mwc_search() {
+    let (ok, out) = ssrand(&["mwc-search", "16", "-n", "2"]);
+    assert!(ok);
+    assert_eq!(
+        out,
+        "50580 0xC593FFFF 1657405439 0.172994\n58443 0xE44AFFFF 1915060223 0.171454\n"
+    );
+
+    // With few candidates there is less to rank.
+    let (ok, out) = ssrand(&["mwc-search", "8", "--lag", "1", "-c", "1"]);
+    assert!(ok);
+    assert_eq!(out.lines().count(), 1);
+
+    let (ok, _) = ssrand(&["mwc-search", "32", "-r", "2"]);
+    assert!(!ok);
+    // The state size mustn't overflow.
+    let output = Command::new(env!("CARGO_BIN_EXE_ssrand"))
+        .args(["mwc-search", "65536", "-r", "65536"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let (ok, _) = ssrand(&["mwc-search"]);
+    assert!(!ok);
+}
+
+#[test]
+fn test_list() {
+    let (ok, out) = ssrand(&["list"]);
+    assert!(ok);
+    assert_eq!(out.lines().count(), 9);
+    assert!(out.starts_with("Cong     1  4294967296 (2^32.0)\n"));
+    assert!(out.contains("\nLFSR113  4  10384593344720504788331840650870785 (2^113.0)\n"));
+}
@@ -0,0 +1,19 @@
+use rand_core::{RngCore, SeedableRng};
+use ssrand::{Cong, KISS, KISS2, LFSR113, LFSR88, MWC1, MWC2, MWC64, SHR3};
+
+#[test]
+fn test_from_os_rng() {
+    // Two generators seeded by the OS are different, except with negligible probability.
+    assert_ne!(KISS::from_os_rng(), KISS::from_os_rng());
+    // rand_core's from_entropy() is available too, through from_seed().
+    assert_ne!(LFSR113::from_entropy(), LFSR113::from_entropy());
+    assert!(KISS2::try_from_os_rng().is_ok());
+
+    // Every generator has them, and works.
+    Cong::from_os_rng().next_u32();
+    SHR3::from_os_rng().next_u32();
+    MWC1::from_os_rng().next_u32();
+    MWC2::from_os_rng().next_u32();
+    MWC64::from_os_rng().next_u32();
+    LFSR88::try_from_os_rng().unwrap().next_u32();
+}
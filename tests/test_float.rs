@@ -0,0 +1,78 @@
+use rand_core::{impls, Error, RngCore};
+use ssrand::float::RngFloat;
+use ssrand::{KISS, LFSR113};
+
+/// A "generator" which always outputs the same value, to check the ends of each interval.
+struct Constant(u32);
+
+impl RngCore for Constant {
+    fn next_u32(&mut self) -> u32 {
+        self.0
+    }
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_lfsr113_c() {
+    // lfsr113.c starts with each component 987654321, which is seed 0x526F68B1 here. Values
+    // from the C double conversion, which are exactly the same.
+    let mut rng = LFSR113::new(0x526F68B1, 0x526F68B1, 0x526F68B1, 0x526F68B1);
+    assert_eq!(rng.next_f64_32(), 0.9202779280021787);
+    assert_eq!(rng.next_f64_32(), 0.27776457089930773);
+    assert_eq!(rng.next_f64_32(), 0.5643350700847805);
+}
+
+#[test]
+fn test_conversions() {
+    let rng = KISS::new(2247183469, 99545079, 3269400377, 3950144837);
+    let outputs: Vec<u32> = {
+        let mut rng = rng.clone();
+        (0..2).map(|_| rng.next_u32()).collect()
+    };
+    let (x, y) = (outputs[0], outputs[1]);
+
+    assert_eq!(rng.clone().next_f32(), (x >> 8) as f32 / 16777216.0);
+    assert_eq!(rng.clone().next_f64_32(), x as f64 / 4294967296.0);
+    assert_eq!(
+        rng.clone().next_f64(),
+        (((x >> 5) as u64) << 26 | (y >> 6) as u64) as f64 / 9007199254740992.0
+    );
+    // next_f64() takes two outputs, the others one.
+    let mut rng_f64 = rng.clone();
+    rng_f64.next_f64();
+    let mut rng_f32 = rng.clone();
+    rng_f32.next_f32();
+    rng_f32.next_f32_open();
+    assert_eq!(rng_f64.next_u32(), rng_f32.next_u32());
+}
+
+#[test]
+fn test_intervals() {
+    let mut low = Constant(0);
+    let mut high = Constant(u32::MAX);
+
+    assert_eq!(low.next_f32(), 0.0);
+    assert_eq!(high.next_f32(), 1.0 - 1.0 / 16777216.0);
+    assert_eq!(low.next_f32_open(), 0.5 / 8388608.0);
+    assert_eq!(high.next_f32_open(), 1.0 - 0.5 / 8388608.0);
+    assert_eq!(low.next_f32_closed(), 0.0);
+    assert_eq!(high.next_f32_closed(), 1.0);
+
+    assert_eq!(low.next_f64(), 0.0);
+    assert_eq!(high.next_f64(), 1.0 - 1.0 / 9007199254740992.0);
+    assert_eq!(low.next_f64_32(), 0.0);
+    assert_eq!(high.next_f64_32(), 1.0 - 1.0 / 4294967296.0);
+    assert_eq!(low.next_f64_open(), 0.5 / 4294967296.0);
+    assert_eq!(high.next_f64_open(), 1.0 - 0.5 / 4294967296.0);
+    assert_eq!(low.next_f64_closed(), 0.0);
+    assert_eq!(high.next_f64_closed(), 1.0);
+}
@@ -0,0 +1,89 @@
+use ::ssrand::gf2poly;
+use rand_core::RngCore;
+
+#[test]
+fn test_arithmetic() {
+    // (x + 1)(x^2 + x + 1) = x^3 + 1
+    assert_eq!(gf2poly::mul(0b11, 0b111), 0b1001);
+    assert_eq!(gf2poly::mul(0b1011, 0), 0);
+    assert_eq!(gf2poly::mul(1 << 100, 1 << 27), 1 << 127);
+
+    assert_eq!(gf2poly::div_rem(0b1001, 0b11), (0b111, 0));
+    assert_eq!(gf2poly::div_rem(0b1000, 0b1011), (0b1, 0b011));
+    assert_eq!(gf2poly::div_rem(0b10, 0b1011), (0, 0b10));
+
+    // x^2 · x^2 = x^4 = x^2 + x modulo x^3 + x + 1
+    assert_eq!(gf2poly::mul_mod(0b100, 0b100, 0b1011), 0b110);
+    assert_eq!(gf2poly::pow_mod(0b10, 4, 0b1011), 0b110);
+    assert_eq!(gf2poly::pow_mod(0b10, 0, 0b1011), 1);
+    // Fermat's little theorem in GF(2^127), with x^127 + x + 1 primitive.
+    let m = (1 << 127) | 0b11;
+    assert_eq!(gf2poly::pow_mod(0b10, u128::MAX >> 1, m), 1);
+
+    assert_eq!(gf2poly::gcd(0b1001, 0b101), 0b11);
+    assert_eq!(gf2poly::gcd(0b1011, 0b111), 1);
+    assert_eq!(gf2poly::gcd(0b1011, 0), 0b1011);
+    assert_eq!(gf2poly::lcm(0b1001, 0b101), 0b11011);
+    assert_eq!(gf2poly::lcm(0b11, 0), 0);
+}
+
+#[test]
+fn test_irreducible_primitive() {
+    // All irreducible polynomials of degree 4: x^4+x+1 and x^4+x^3+1 are primitive,
+    // x^4+x^3+x^2+x+1 is not.
+    let irreducible: Vec<u128> = (0b10000..0b100000)
+        .filter(|&p| gf2poly::is_irreducible(p))
+        .collect();
+    assert_eq!(irreducible, [0b10011, 0b11001, 0b11111]);
+    let primitive: Vec<u128> = (0b10000..0b100000)
+        .filter(|&p| gf2poly::is_primitive(p))
+        .collect();
+    assert_eq!(primitive, [0b10011, 0b11001]);
+
+    // There are φ(2^8-1)/8 = 16 primitive polynomials of degree 8, and 30 irreducible ones.
+    let count = (0x100..0x200).filter(|&p| gf2poly::is_primitive(p)).count();
+    assert_eq!(count, 16);
+    let count = (0x100..0x200)
+        .filter(|&p| gf2poly::is_irreducible(p))
+        .count();
+    assert_eq!(count, 30);
+
+    assert!(gf2poly::is_primitive(0b11));
+    assert!(!gf2poly::is_primitive(0b10));
+    assert!(gf2poly::is_irreducible(0b10));
+    assert!(!gf2poly::is_irreducible(1));
+    assert!(!gf2poly::is_irreducible(0));
+    // x^32 + x^22 + x^2 + x + 1
+    assert!(gf2poly::is_primitive((1 << 32) | (1 << 22) | 0b111));
+    // x^64 + x^4 + x^3 + x + 1
+    assert!(gf2poly::is_primitive((1 << 64) | 0b11011));
+    // x^89 + x^38 + 1. 2^89-1 is prime, so it needs no trial division.
+    assert!(gf2poly::is_primitive((1 << 89) | (1 << 38) | 1));
+}
+
+#[test]
+fn test_berlekamp_massey() {
+    assert_eq!(gf2poly::berlekamp_massey(core::iter::empty()), 1);
+    assert_eq!(gf2poly::berlekamp_massey([false; 10].iter().copied()), 1);
+    assert_eq!(gf2poly::berlekamp_massey([true; 10].iter().copied()), 0b11);
+    // A single 1 at the end needs the longest recurrence.
+    let bits = [false, false, false, false, true];
+    let minimal_polynomial = gf2poly::berlekamp_massey(bits.iter().copied());
+    assert_eq!(gf2poly::degree(minimal_polynomial), Some(5));
+}
+
+/// Linear complexity of bit 0 of the output.
+fn linear_complexity<R: RngCore>(rng: &mut R, n: usize) -> u32 {
+    let bits = (0..n).map(|_| rng.next_u32() & 1 != 0);
+    gf2poly::degree(gf2poly::berlekamp_massey(bits)).unwrap()
+}
+
+#[test]
+fn test_generator_linear_complexity() {
+    let mut rng = ssrand::SHR3::new(3360276411);
+    assert_eq!(linear_complexity(&mut rng, 200), 32);
+    let mut rng = ssrand::LFSR88::new(1, 2, 3);
+    assert_eq!(linear_complexity(&mut rng, 250), 88);
+    let mut rng = ssrand::LFSR113::new(1, 2, 3, 4);
+    assert_eq!(linear_complexity(&mut rng, 250), 113);
+}
@@ -1,5 +1,5 @@
-use ssrand::RngJumpAhead;
 use rand_core::RngCore;
+use ssrand::RngJumpAhead;
 
 #[test]
 fn test_kiss_million() {
@@ -135,3 +135,335 @@ fn test_lfsr88_million() {
     assert_eq!(rng_ja, rng);
     assert_eq!(rng_ja.next_u32(), rng.next_u32());
 }
+
+#[test]
+fn test_verify_period() {
+    assert!(ssrand::SHR3::verify_period());
+    assert!(ssrand::MWC2::verify_period());
+    assert!(ssrand::MWC64::verify_period());
+    assert!(ssrand::LFSR88::verify_period());
+    assert!(ssrand::LFSR113::verify_period());
+}
+
+#[test]
+fn test_const_jumped() {
+    const KISS_JUMPED: ssrand::KISS = ssrand::KISS::new(1, 2, 3, 4).jumped(1_000_000);
+    let mut rng = ssrand::KISS::new(1, 2, 3, 4);
+    rng.jumpahead(1_000_000);
+    assert_eq!(rng, KISS_JUMPED);
+
+    for &n in [0_i128, 1, 12345, -1, -1_000_000, 1 << 100, i128::MIN].iter() {
+        let mut rng = ssrand::Cong::new(2051391225);
+        rng.jumpahead(n);
+        assert_eq!(ssrand::Cong::new(2051391225).jumped(n), rng);
+        let mut rng = ssrand::SHR3::new(0);
+        rng.jumpahead(n);
+        assert_eq!(ssrand::SHR3::new(0).jumped(n), rng);
+        let mut rng = ssrand::MWC2::new(0, 0xFFFFFFFF);
+        rng.jumpahead(n);
+        assert_eq!(ssrand::MWC2::new(0, 0xFFFFFFFF).jumped(n), rng);
+        let mut rng = ssrand::MWC1::new(12345, 65435);
+        rng.jumpahead(n);
+        assert_eq!(ssrand::MWC1::new(12345, 65435).jumped(n), rng);
+        let mut rng = ssrand::KISS::new(2247183469, 99545079, 3269400377, 3950144837);
+        rng.jumpahead(n);
+        assert_eq!(
+            ssrand::KISS::new(2247183469, 99545079, 3269400377, 3950144837).jumped(n),
+            rng
+        );
+        let mut rng = ssrand::MWC64::new(0, 0);
+        rng.jumpahead(n);
+        assert_eq!(ssrand::MWC64::new(0, 0).jumped(n), rng);
+        let mut rng = ssrand::KISS2::new(1, 2, 3, 4);
+        rng.jumpahead(n);
+        assert_eq!(ssrand::KISS2::new(1, 2, 3, 4).jumped(n), rng);
+        let mut rng = ssrand::LFSR88::new(0, 0, 0);
+        rng.jumpahead(n);
+        assert_eq!(ssrand::LFSR88::new(0, 0, 0).jumped(n), rng);
+        let mut rng = ssrand::LFSR113::new(1, 2, 3, 4);
+        rng.jumpahead(n);
+        assert_eq!(ssrand::LFSR113::new(1, 2, 3, 4).jumped(n), rng);
+    }
+}
+
+/// Jumping by the extremes of every integer type matches the same jump as i128, and doesn't panic.
+fn check_jumpahead_types<R>(rng: R)
+where
+    R: RngJumpAhead + Clone + PartialEq + core::fmt::Debug,
+{
+    let jumped = |n: i128| {
+        let mut r = rng.clone();
+        r.jumpahead(n);
+        r
+    };
+    macro_rules! check_type {
+        ($t:ty) => {
+            for &n in [<$t>::MIN, <$t>::MAX, 0, 1].iter() {
+                let mut r = rng.clone();
+                r.jumpahead(n);
+                assert_eq!(r, jumped(n as i128), "{} {}", stringify!($t), n);
+            }
+        };
+    }
+    check_type!(i8);
+    check_type!(i16);
+    check_type!(i32);
+    check_type!(i64);
+    check_type!(i128);
+    check_type!(isize);
+    check_type!(u8);
+    check_type!(u16);
+    check_type!(u32);
+    check_type!(u64);
+    check_type!(usize);
+
+    // u128::MAX doesn't fit into i128, so jump in two parts.
+    let mut r = rng.clone();
+    r.jumpahead(u128::MAX);
+    let mut expected = jumped(i128::MAX);
+    expected.jumpahead(i128::MAX);
+    expected.jumpahead(1);
+    assert_eq!(r, expected);
+}
+
+#[test]
+fn test_jumpahead_types() {
+    check_jumpahead_types(ssrand::Cong::new(2051391225));
+    check_jumpahead_types(ssrand::SHR3::new(3360276411));
+    check_jumpahead_types(ssrand::MWC2::new(12345, 65435));
+    check_jumpahead_types(ssrand::MWC1::new(12345, 65435));
+    check_jumpahead_types(ssrand::KISS::new(
+        2247183469, 99545079, 3269400377, 3950144837,
+    ));
+    check_jumpahead_types(ssrand::MWC64::new(12345, 65435));
+    check_jumpahead_types(ssrand::KISS2::new(1, 2, 3, 4));
+    check_jumpahead_types(ssrand::LFSR88::new(1, 2, 3));
+    check_jumpahead_types(ssrand::LFSR113::new(1, 2, 3, 4));
+}
+
+#[test]
+fn test_jumpahead_biguint() {
+    // A stride of 3·2^127, beyond u128, equals three jumps of 2^127.
+    let stride = ssrand::math::BigUint::<4>::from_u128(1 << 127)
+        .checked_mul_u64(3)
+        .unwrap();
+    let mut expected = ssrand::LFSR113::new(1, 2, 3, 4);
+    for _ in 0..3 {
+        expected.jumpahead(1_u128 << 127);
+    }
+    let mut rng = ssrand::LFSR113::new(1, 2, 3, 4);
+    rng.jumpahead(stride);
+    assert_eq!(rng, expected);
+
+    let mut expected = ssrand::KISS2::new(1, 2, 3, 4);
+    for _ in 0..3 {
+        expected.jumpahead(1_u128 << 127);
+    }
+    let mut rng = ssrand::KISS2::new(1, 2, 3, 4);
+    rng.jumpahead(stride);
+    assert_eq!(rng, expected);
+
+    // Counts that fit agree with the primitive types.
+    for &n in [0_u128, 1, 1_000_000, u64::MAX as u128 + 12345, u128::MAX].iter() {
+        let mut expected = ssrand::KISS::new(2247183469, 99545079, 3269400377, 3950144837);
+        expected.jumpahead(n);
+        let mut rng = ssrand::KISS::new(2247183469, 99545079, 3269400377, 3950144837);
+        rng.jumpahead(ssrand::math::BigUint::<2>::from_u128(n));
+        assert_eq!(rng, expected);
+    }
+}
+
+/// `jumpahead_pow2(k)` matches `jumpahead()` by 2^k, within and beyond the range of u128.
+fn check_jumpahead_pow2<R>(rng: R)
+where
+    R: RngJumpAhead + Clone + PartialEq + core::fmt::Debug,
+{
+    for k in (0..128)
+        .chain(128..300)
+        .chain([1000, 12345].iter().copied())
+    {
+        let mut r = rng.clone();
+        r.jumpahead_pow2(k);
+        let mut expected = rng.clone();
+        if k < 128 {
+            expected.jumpahead(1_u128 << k);
+        } else {
+            let n = ssrand::math::BigUint::<256>::from_u128(1).checked_shl(k as usize);
+            expected.jumpahead(n.unwrap());
+        }
+        assert_eq!(r, expected, "k = {}", k);
+    }
+
+    // 2^k steps is 2^(k-1) steps twice.
+    let mut r = rng.clone();
+    r.jumpahead_pow2(u32::MAX);
+    let mut expected = rng;
+    expected.jumpahead_pow2(u32::MAX - 1);
+    expected.jumpahead_pow2(u32::MAX - 1);
+    assert_eq!(r, expected);
+}
+
+#[test]
+fn test_jumpahead_pow2() {
+    check_jumpahead_pow2(ssrand::Cong::new(2051391225));
+    check_jumpahead_pow2(ssrand::SHR3::new(3360276411));
+    check_jumpahead_pow2(ssrand::MWC2::new(12345, 65435));
+    check_jumpahead_pow2(ssrand::MWC1::new(0, 0));
+    check_jumpahead_pow2(ssrand::KISS::new(
+        2247183469, 99545079, 3269400377, 3950144837,
+    ));
+    check_jumpahead_pow2(ssrand::MWC64::new(12345, 65435));
+    check_jumpahead_pow2(ssrand::KISS2::new(1, 2, 3, 4));
+    check_jumpahead_pow2(ssrand::LFSR88::new(1, 2, 3));
+    check_jumpahead_pow2(ssrand::LFSR113::new(0, 0, 0, 0));
+}
+
+/// A generator outside the crate, with only `jumpahead()`
+#[derive(Clone, Debug, PartialEq)]
+struct Counter(u32);
+
+impl RngJumpAhead for Counter {
+    fn jumpahead<N>(&mut self, n: N)
+    where
+        N: ssrand::math::JumpCount,
+    {
+        // A cycle of odd length, so that 2^k doesn't just reduce to zero.
+        let n_mod: u32 = ssrand::math::modulo(n, 1_000_003);
+        self.0 = (self.0 + n_mod) % 1_000_003;
+    }
+}
+
+#[test]
+fn test_jumpahead_pow2_default() {
+    check_jumpahead_pow2(Counter(0));
+    let mut counter = Counter(5);
+    counter.jumpahead_pow2(20);
+    assert_eq!(counter, Counter(48578));
+}
+
+#[test]
+fn test_dyn_rng() {
+    use ssrand::{DynRng, RngJumpAheadDyn};
+
+    let mut table: Vec<Box<dyn DynRng>> = vec![
+        Box::new(ssrand::Cong::new(2051391225)),
+        Box::new(ssrand::KISS::new(
+            2247183469, 99545079, 3269400377, 3950144837,
+        )),
+        Box::new(ssrand::LFSR113::new(1, 2, 3, 4)),
+        Box::new(ssrand::anyrng::AnyRng::new("MWC64", &[12345, 65435]).unwrap()),
+    ];
+    let mut expected: Vec<Box<dyn DynRng>> = vec![
+        Box::new(ssrand::Cong::new(2051391225)),
+        Box::new(ssrand::KISS::new(
+            2247183469, 99545079, 3269400377, 3950144837,
+        )),
+        Box::new(ssrand::LFSR113::new(1, 2, 3, 4)),
+        Box::new(ssrand::MWC64::new(12345, 65435)),
+    ];
+    let stride = ssrand::math::BigUint::<4>::from_limbs([5, 0, 0, 1]);
+    for (rng, expected) in table.iter_mut().zip(expected.iter_mut()) {
+        rng.jumpahead_i128(-1_000_000);
+        rng.jumpahead_u128(1 << 100);
+        rng.jumpahead_u256(stride);
+        rng.jumpahead_pow2_dyn(200);
+        expected.jumpahead_u128((1 << 100) - 1_000_000);
+        expected.jumpahead_u256(stride);
+        expected.jumpahead_u256(ssrand::math::BigUint::from_limbs([0, 0, 0, 1 << 8]));
+        assert_eq!(rng.next_u32(), expected.next_u32());
+        assert_eq!(rng.next_u64(), expected.next_u64());
+    }
+
+    // Same as the generic jumpahead().
+    let mut rng = ssrand::KISS2::new(1, 2, 3, 4);
+    let mut expected = rng.clone();
+    rng.jumpahead_u256(stride);
+    expected.jumpahead(stride);
+    assert_eq!(rng, expected);
+    rng.jumpahead_pow2_dyn(1000);
+    expected.jumpahead_pow2(1000);
+    assert_eq!(rng, expected);
+}
+
+fn hash_of<T: std::hash::Hash>(value: &T) -> u64 {
+    use std::hash::Hasher;
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn outputs<R: RngCore + Clone>(rng: &R) -> Vec<u32> {
+    let mut rng = rng.clone();
+    (0..8).map(|_| rng.next_u32()).collect()
+}
+
+/// Generators compare equal (with equal hashes) exactly when they produce the same output.
+fn check_canonical<R>(a: R, b: R)
+where
+    R: RngCore + Clone + Eq + std::hash::Hash + core::fmt::Debug,
+{
+    let same_output = outputs(&a) == outputs(&b);
+    assert_eq!(a == b, same_output, "{:?} {:?}", a, b);
+    if same_output {
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+}
+
+#[test]
+fn test_canonical() {
+    assert_eq!(ssrand::SHR3::new(0), ssrand::SHR3::new(0xFFFFFFFF));
+    assert_eq!(
+        ssrand::SHR3::new(0).canonical(),
+        ssrand::SHR3::new(0xFFFFFFFF)
+    );
+    assert_eq!(
+        format!("{:?}", ssrand::SHR3::new(0).canonical()),
+        "SHR3 { shr3: 4294967295 }"
+    );
+    assert_ne!(ssrand::SHR3::new(1), ssrand::SHR3::new(2));
+    assert_eq!(ssrand::Cong::new(0).canonical(), ssrand::Cong::new(0));
+
+    let values = [
+        0_u32, 1, 2, 7, 8, 15, 127, 128, 0xFFFF, 0x10000, 589823999, 1179647998, 1179647999,
+        1179648000, 1179648001, 2359295998, 2359295999, 2422800383, 2422800384, 2422865918,
+        3538943997, 3538943998, 0xFFFEFFFF, 0xFFFF0000, 0xFFFFFFFE, 0xFFFFFFFF,
+    ];
+    for &x in values.iter() {
+        for &y in values.iter() {
+            check_canonical(ssrand::SHR3::new(x), ssrand::SHR3::new(y));
+            check_canonical(ssrand::MWC2::new(x, 12345), ssrand::MWC2::new(y, 12345));
+            check_canonical(ssrand::MWC2::new(12345, x), ssrand::MWC2::new(12345, y));
+            check_canonical(ssrand::MWC1::new(12345, x), ssrand::MWC1::new(12345, y));
+            check_canonical(ssrand::MWC64::new(x, 12345), ssrand::MWC64::new(y, 12345));
+            check_canonical(ssrand::MWC64::new(x, y), ssrand::MWC64::new(y, x));
+            check_canonical(ssrand::LFSR88::new(x, y, 5), ssrand::LFSR88::new(y, x, 5));
+            check_canonical(
+                ssrand::LFSR113::new(x, 9, y, 5),
+                ssrand::LFSR113::new(y, 9, x, 5),
+            );
+        }
+
+        // The canonical form produces the same output, and is its own canonical form.
+        let kiss = ssrand::KISS::new(x, x ^ 0x5A5A5A5A, x, !x);
+        assert_eq!(outputs(&kiss.canonical()), outputs(&kiss));
+        assert_eq!(
+            format!("{:?}", kiss.canonical().canonical()),
+            format!("{:?}", kiss.canonical())
+        );
+        let kiss2 = ssrand::KISS2::new(x, !x, x, !x);
+        assert_eq!(outputs(&kiss2.canonical()), outputs(&kiss2));
+        assert_eq!(
+            format!("{:?}", kiss2.canonical().canonical()),
+            format!("{:?}", kiss2.canonical())
+        );
+        let lfsr113 = ssrand::LFSR113::new(x, !x, x, !x);
+        assert_eq!(outputs(&lfsr113.canonical()), outputs(&lfsr113));
+        assert_eq!(lfsr113.canonical(), lfsr113);
+    }
+
+    // LFSR components below the minimum are replaced, and their low bits don't matter. These
+    // seeds give components 0xFFFFFFFE, 0xFFFFFFF8 and 0xFFFFFFF0.
+    let lfsr88 = ssrand::LFSR88::new(0x0001FFFE, 0x0007FFF8, 0x000FFFF0);
+    assert_eq!(ssrand::LFSR88::new(0, 0, 0), lfsr88);
+    assert_eq!(hash_of(&ssrand::LFSR88::new(0, 0, 0)), hash_of(&lfsr88));
+}
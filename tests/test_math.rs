@@ -1,4 +1,5 @@
 use ::ssrand::math;
+use rand_core::RngCore;
 
 #[test]
 fn test_mul_mod_generic() {
@@ -314,6 +315,55 @@ fn test_modulo() {
 
     let result = math::modulo(1_000_000_000_000_000_000_i64, 3442199977_u32);
     assert_eq!(result, 3019173309_u32);
+
+    // Negative multiples of m reduce to 0, not m.
+    assert_eq!(math::modulo(-206_i32, 103_u8), 0_u8);
+    assert_eq!(math::modulo(-(1_i64 << 32), 1_u64 << 32), 0_u64);
+    assert_eq!(math::modulo(i8::MIN, 128_u8), 0_u8);
+
+    // Extremes of mixed type widths.
+    assert_eq!(math::modulo(i128::MIN, 255_u8), 127_u8);
+    assert_eq!(math::modulo(i128::MIN, u128::MAX), (1_u128 << 127) - 1);
+    assert_eq!(math::modulo(u128::MAX, 7_u8), 3_u8);
+    assert_eq!(math::modulo(i8::MIN, u128::MAX), u128::MAX - 128);
+    assert_eq!(math::modulo(isize::MIN, 3_usize), 1_usize);
+    assert_eq!(math::modulo(-1_i64, 1_u8), 0_u8);
+}
+
+#[test]
+fn test_checked_modulo() {
+    assert_eq!(
+        math::checked_modulo(-1_000_000_000_i32, 207_u8),
+        Some(26_u8)
+    );
+    assert_eq!(math::checked_modulo(i128::MIN, 255_u8), Some(127_u8));
+    assert_eq!(math::checked_modulo(12345_u32, 0_u8), None);
+    assert_eq!(math::checked_modulo(-12345_i64, 0_u128), None);
+    assert_eq!(math::checked_modulo(0_i8, 0_u64), None);
+
+    // Agrees with i128::rem_euclid over mixed types.
+    for &a in [
+        i64::MIN,
+        -1_000_000_007,
+        -65536,
+        -1,
+        0,
+        1,
+        255,
+        65537,
+        i64::MAX,
+    ]
+    .iter()
+    {
+        for &m in [1_u16, 2, 3, 255, 256, 40239, u16::MAX].iter() {
+            let expected = (a as i128).rem_euclid(m as i128) as u16;
+            assert_eq!(math::checked_modulo(a, m), Some(expected));
+            assert_eq!(
+                math::checked_modulo(a as i32, m as u64),
+                Some((a as i32 as i128).rem_euclid(m as i128) as u64)
+            );
+        }
+    }
 }
 
 #[test]
@@ -362,6 +412,26 @@ fn test_pow_mod() {
     assert_eq!(result, 0x7C4A71C0F57CAAB0_u64);
 }
 
+#[test]
+fn test_checked_mul_mod_pow_mod() {
+    assert_eq!(
+        math::checked_mul_mod(123456789_u32, 3111222333, 0x9068FFFF),
+        Some(1473911797)
+    );
+    assert_eq!(math::checked_mul_mod(123456789_u32, 3111222333, 0), None);
+    assert_eq!(math::checked_mul_mod(u128::MAX, u128::MAX, 0), None);
+    assert_eq!(math::checked_mul_mod(5_u8, 7, 1), Some(0));
+
+    assert_eq!(
+        math::checked_pow_mod(648518821_u32, 12345_u32, 3288555137_u32),
+        Some(2953876344)
+    );
+    assert_eq!(math::checked_pow_mod(87_u8, 12345_u16, 0), None);
+    assert_eq!(math::checked_pow_mod(87_u64, 0_u16, 0), None);
+    assert_eq!(math::checked_pow_mod(87_u128, 12345_u32, 0), None);
+    assert_eq!(math::checked_pow_mod(87_usize, 12345_u32, 1), Some(0));
+}
+
 #[test]
 fn test_wrapping_geom_series() {
     let result = math::wrapping_geom_series(21345_u32, 12345_u16);
@@ -376,3 +446,478 @@ fn test_wrapping_geom_series() {
     let result = math::wrapping_geom_series(69069_u32, 1_000_000_000_000_000_000_u64);
     assert_eq!(result, 629932032_u32);
 }
+
+#[test]
+fn test_prime_factors() {
+    let factors: Vec<u32> = math::prime_factors(0xFFFFFFFF_u32).collect();
+    assert_eq!(factors, [3, 5, 17, 257, 65537]);
+    let factors: Vec<u32> = math::prime_factors((1_u32 << 31) - 1).collect();
+    assert_eq!(factors, [0x7FFFFFFF]);
+    let factors: Vec<u32> = math::prime_factors((1_u32 << 29) - 1).collect();
+    assert_eq!(factors, [233, 1103, 2089]);
+    let factors: Vec<u8> = math::prime_factors(2_u8 * 2 * 3 * 3 * 7).collect();
+    assert_eq!(factors, [2, 3, 7]);
+    let factors: Vec<u64> = math::prime_factors(1_u64 << 63).collect();
+    assert_eq!(factors, [2]);
+    let factors: Vec<u128> = math::prime_factors((1_u128 << 64) + 1).collect();
+    assert_eq!(factors, [274177, 67280421310721]);
+    assert_eq!(math::prime_factors(1_u16).next(), None);
+    assert_eq!(math::prime_factors(0_u16).next(), None);
+    assert_eq!(
+        math::prime_factors(0xFFF1_u16).collect::<Vec<_>>(),
+        [0xFFF1]
+    );
+}
+
+#[test]
+fn test_const_fns() {
+    const POW_MOD: u32 = math::pow_mod_u32(648518821, 12345, 3288555137);
+    assert_eq!(POW_MOD, 2953876344);
+    const POW_MOD_64: u64 =
+        math::pow_mod_u64(0xFFFFFFFFFFFFFFFC, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF);
+    assert_eq!(POW_MOD_64, 0x7C4A71C0F57CAAB0);
+    const WRAPPING_POW: u32 = math::wrapping_pow_u32(0xFFFFFFFD, 0xFFFFFFFF);
+    assert_eq!(WRAPPING_POW, 0x55555555);
+    const GEOM_SERIES: u32 = math::wrapping_geom_series_u32(69069, 1_000_000_000_000_000_000);
+    assert_eq!(GEOM_SERIES, 629932032);
+
+    let mut seed = 0x0123456789ABCDEF_u64;
+    for _ in 0..100 {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let a = seed;
+        let m = seed.rotate_left(17) | 1;
+        let n = seed.rotate_left(40);
+        assert_eq!(
+            math::mul_mod_u64(a % m, n % m, m),
+            math::mul_mod(a % m, n % m, m)
+        );
+        assert_eq!(math::pow_mod_u64(a, n as u128, m), math::pow_mod(a, n, m));
+        assert_eq!(
+            math::wrapping_pow_u64(a, n as u128),
+            math::wrapping_pow(a, n)
+        );
+        assert_eq!(
+            math::wrapping_geom_series_u64(a, n as u128),
+            math::wrapping_geom_series(a, n)
+        );
+        let a = a as u32;
+        let m = m as u32;
+        assert_eq!(
+            math::mul_mod_u32(a % m, (n as u32) % m, m),
+            math::mul_mod(a % m, (n as u32) % m, m)
+        );
+        assert_eq!(math::pow_mod_u32(a, n as u128, m), math::pow_mod(a, n, m));
+        assert_eq!(
+            math::pow_mod_u128(a as u128 * m as u128, n as u128, u128::MAX - 58),
+            math::pow_mod(a as u128 * m as u128, n as u128, u128::MAX - 58)
+        );
+    }
+}
+
+#[test]
+fn test_montgomery() {
+    let mut rng = ssrand::KISS2::new(1, 2, 3, 4);
+    let next_u128 =
+        |rng: &mut ssrand::KISS2| ((rng.next_u64() as u128) << 64) | rng.next_u64() as u128;
+    for i in 0..1000 {
+        // Moduli of various sizes, including near the maximum.
+        let shift = i % 64;
+        let m = (rng.next_u64() >> shift) | 1;
+        let a = rng.next_u64();
+        let b = rng.next_u64();
+        let montgomery = math::Montgomery::<u64>::new(m).unwrap();
+        assert_eq!(montgomery.modulus(), m);
+        assert_eq!(montgomery.mul_mod(a, b), math::mul_mod_generic(a, b, m));
+        assert_eq!(
+            montgomery.from_montgomery(montgomery.to_montgomery(a)),
+            a % m
+        );
+        assert_eq!(math::pow_mod(a, b, m), math::pow_mod_generic(a, b, m));
+        assert_eq!(
+            math::pow_mod(a, b, (m & !1).max(2)),
+            math::pow_mod_generic(a, b, (m & !1).max(2))
+        );
+
+        let shift = i % 128;
+        let m = (next_u128(&mut rng) >> shift) | 1;
+        let a = next_u128(&mut rng);
+        let b = next_u128(&mut rng);
+        assert_eq!(math::mul_mod(a, b, m), math::mul_mod_generic(a, b, m));
+        assert_eq!(
+            math::pow_mod(a, b as u32, m),
+            math::pow_mod_generic(a, b as u32, m)
+        );
+        assert_eq!(
+            math::mul_mod(a, b, (m & !1).max(2)),
+            math::mul_mod_generic(a, b, (m & !1).max(2))
+        );
+        assert_eq!(
+            math::pow_mod(a, b, (m & !1).max(2)),
+            math::pow_mod_generic(a, b, (m & !1).max(2))
+        );
+
+        let m = m as usize;
+        let a = a as usize;
+        let b = b as usize;
+        assert_eq!(math::mul_mod(a, b, m), math::mul_mod_generic(a, b, m));
+        assert_eq!(math::pow_mod(a, b, m), math::pow_mod_generic(a, b, m));
+    }
+
+    // Edge cases
+    for &m in [1_u64, 3, u64::MAX, u64::MAX - 2].iter() {
+        for &a in [0, 1, m - 1, m, u64::MAX].iter() {
+            for &b in [0, 1, m - 1, u64::MAX].iter() {
+                let montgomery = math::Montgomery::<u64>::new(m).unwrap();
+                assert_eq!(montgomery.mul_mod(a, b), math::mul_mod_generic(a, b, m));
+                let m = m as u128;
+                let a = a as u128 * 0xFFFFFFFF00000001;
+                let b = b as u128;
+                assert_eq!(math::mul_mod(a, b, m), math::mul_mod_generic(a, b, m));
+                assert_eq!(math::pow_mod(a, b, m), math::pow_mod_generic(a, b, m));
+            }
+        }
+    }
+    assert_eq!(math::pow_mod(5_u64, 0_u8, u64::MAX), 1);
+    assert_eq!(math::pow_mod(u128::MAX, 2_u8, u128::MAX), 0);
+    assert!(math::Montgomery::<u128>::new(0).is_none());
+}
+
+#[test]
+fn test_gcd() {
+    assert_eq!(math::gcd(0_u8, 0), 0);
+    assert_eq!(math::gcd(7_u8, 0), 7);
+    assert_eq!(math::gcd(0xFFFFFFFF_u32, 0xFFFF), 0xFFFF);
+    assert_eq!(math::gcd(u128::MAX, u128::MAX - 1), 1);
+
+    let cases: [(u128, u128); 7] = [
+        (240, 46),
+        (46, 240),
+        (0, 5),
+        (5, 0),
+        (7, 7),
+        (u128::MAX, u128::MAX - 1),
+        (u128::MAX, 0xFFFFFFFFFFFFFFFF),
+    ];
+    for &(a, b) in cases.iter() {
+        let (g, x, y) = math::extended_gcd(a, b);
+        assert_eq!(g, math::gcd(a, b));
+        // Check a·x + b·y = g, with wrapping arithmetic.
+        let sum = a
+            .wrapping_mul(x as u128)
+            .wrapping_add(b.wrapping_mul(y as u128));
+        assert_eq!(sum, g, "{} {}", a, b);
+    }
+    assert_eq!(math::extended_gcd(u8::MAX, u8::MAX - 1), (1, 1, -1));
+}
+
+#[test]
+fn test_mod_inverse() {
+    assert_eq!(math::mod_inverse(3_u8, 7), Some(5));
+    assert_eq!(math::mod_inverse(10_u8, 7), Some(5));
+    assert_eq!(math::mod_inverse(0_u8, 7), None);
+    assert_eq!(math::mod_inverse(5_u8, 1), Some(0));
+    assert_eq!(math::mod_inverse(5_u8, 0), None);
+    assert_eq!(math::mod_inverse(6_u8, 9), None);
+
+    let mut rng = ssrand::KISS2::new(5, 6, 7, 8);
+    for _ in 0..1000 {
+        let m = rng.next_u64() | 1;
+        let a = rng.next_u64();
+        match math::mod_inverse(a, m) {
+            Some(inverse) => {
+                assert!(inverse < m);
+                assert_eq!(math::mul_mod(a, inverse, m), 1 % m);
+            }
+            None => assert_ne!(math::gcd(a, m), 1),
+        }
+        let a = a | 1;
+        let inverse = math::wrapping_inverse(a).unwrap();
+        assert_eq!(a.wrapping_mul(inverse), 1);
+        let a = ((a as u128) << 64) | a as u128;
+        let inverse = math::wrapping_inverse(a).unwrap();
+        assert_eq!(a.wrapping_mul(inverse), 1);
+    }
+    assert_eq!(math::wrapping_inverse(0_u8), None);
+    assert_eq!(math::wrapping_inverse(u16::MAX), Some(u16::MAX));
+
+    // Step Cong backwards.
+    let mut rng = ssrand::Cong::new(2051391225);
+    let previous = rng.next_u32();
+    let current = rng.next_u32();
+    let m_inverse = math::wrapping_inverse(69069_u32).unwrap();
+    assert_eq!(
+        m_inverse.wrapping_mul(current.wrapping_sub(12345)),
+        previous
+    );
+}
+
+#[test]
+fn test_discrete_log() {
+    for target in 1..7_u32 {
+        let x = math::discrete_log(3_u32, target, 7, 6).unwrap();
+        assert!(x < 6);
+        assert_eq!(math::pow_mod(3_u32, x, 7), target);
+    }
+    assert_eq!(math::discrete_log(2_u32, 4, 7, 6), Some(2));
+    assert_eq!(math::discrete_log(2_u32, 3, 7, 6), None);
+    assert_eq!(math::discrete_log(1_u32, 1, 7, 6), Some(0));
+    assert_eq!(math::discrete_log(5_u32, 0, 1, 1), Some(0));
+
+    // 2^61-1 is prime, and 2^61-2 has only small prime factors.
+    let m = (1_u64 << 61) - 1;
+    let mut rng = ssrand::KISS2::new(1, 2, 3, 4);
+    for _ in 0..10 {
+        let x = rng.next_u64() % (m - 1);
+        let target = math::pow_mod(37, x, m);
+        let log = math::discrete_log(37, target, m, m - 1).unwrap();
+        assert!(log < m - 1);
+        assert_eq!(math::pow_mod(37, log, m), target);
+    }
+
+    // Distance between two states of the upper MWC2 component, which has a large prime factor
+    // in its order.
+    let mwc_m = 36969_u32;
+    let mwc_mod = (mwc_m << 16) - 1;
+    let cycle_len = (mwc_m << 15) - 1;
+    let state = 123456789_u32;
+    let n = 987654321_u32;
+    let jumped = math::mul_mod(math::pow_mod(mwc_m, n, mwc_mod), state, mwc_mod);
+    let ratio = math::mul_mod(jumped, math::mod_inverse(state, mwc_mod).unwrap(), mwc_mod);
+    let distance = math::discrete_log(mwc_m, ratio, mwc_mod, mwc_mod - 1).unwrap();
+    assert_eq!(distance, n % cycle_len);
+}
+
+#[test]
+fn test_is_prime() {
+    // Compare with trial division for small numbers.
+    for n in 0..10_000_u32 {
+        let trial = n >= 2
+            && (2..n)
+                .take_while(|d| d * d <= n)
+                .all(|d| n % d != 0);
+        assert_eq!(math::is_prime(n), trial, "{}", n);
+        assert_eq!(math::is_prime(n as u16), trial, "{}", n);
+    }
+    assert!(math::is_prime(251_u8));
+    assert!(!math::is_prime(255_u8));
+
+    assert!(math::is_prime(0xFFFFFFFB_u32));
+    assert!(math::is_prime((1_u64 << 61) - 1));
+    assert!(math::is_prime(0xFFFFFFFFFFFFFFC5_u64));
+    assert!(!math::is_prime(u64::MAX));
+    assert!(math::is_prime((1_u128 << 89) - 1));
+    assert!(math::is_prime((1_u128 << 127) - 1));
+    assert!(!math::is_prime((1_u128 << 67) - 1));
+    assert!(math::is_prime(0xFFFFFFFFFFFFFFC5_usize as u64 as usize));
+
+    // Strong pseudoprimes to several of the smallest prime bases, and a Carmichael number.
+    assert!(!math::is_prime(561_u32));
+    assert!(!math::is_prime(2047_u32));
+    assert!(!math::is_prime(3215031751_u32));
+    assert!(!math::is_prime(3825123056546413051_u64));
+    assert!(!math::is_prime(318665857834031151167461_u128));
+}
+
+#[test]
+fn test_is_safe_prime() {
+    let safe_primes: Vec<u32> = (0..110).filter(|&p| math::is_safe_prime(p)).collect();
+    assert_eq!(safe_primes, [5, 7, 11, 23, 47, 59, 83, 107]);
+
+    assert!(math::is_mwc_multiplier(36969_u32, 16));
+    assert!(math::is_mwc_multiplier(18000_u32, 16));
+    assert!(math::is_mwc_multiplier(698769069_u64, 32));
+    assert!(!math::is_mwc_multiplier(36970_u32, 16));
+    // Doesn't fit.
+    assert!(!math::is_mwc_multiplier(698769069_u32, 32));
+    assert!(!math::is_mwc_multiplier(0x10000_u32, 16));
+    assert!(!math::is_mwc_multiplier(0_u32, 16));
+}
+
+#[test]
+fn test_isqrt() {
+    for n in 0..10_000_u32 {
+        let root = math::isqrt(n);
+        assert!(root * root <= n && (root + 1) * (root + 1) > n, "{}", n);
+    }
+    assert_eq!(math::isqrt(u8::MAX), 15);
+    assert_eq!(math::isqrt(u32::MAX), 0xFFFF);
+    assert_eq!(math::isqrt(u64::MAX), 0xFFFFFFFF);
+    assert_eq!(math::isqrt(1_u128 << 126), 1 << 63);
+    assert_eq!(math::isqrt((1_u128 << 126) - 1), (1 << 63) - 1);
+}
+
+#[test]
+fn test_root_f64() {
+    for n in 1..=8 {
+        for &x in [1e-300, 0.001, 0.5, 1.0, 2.0, 12345.678, 4294967296.0, 1e300].iter() {
+            let root = math::root_f64(x, n);
+            assert!((root.powi(n as i32) - x).abs() <= 1e-13 * x, "{} {}", x, n);
+            assert!((root - x.powf(1.0 / n as f64)).abs() <= 1e-12 * root);
+        }
+    }
+    assert_eq!(math::root_f64(0.0, 3), 0.0);
+    assert_eq!(math::root_f64(16.0, 4), 2.0);
+}
+
+#[test]
+fn test_biguint() {
+    type U256 = math::BigUint<4>;
+    let n = U256::from_u128(u128::MAX);
+    assert_eq!(n.limbs(), [u64::MAX, u64::MAX, 0, 0]);
+    assert_eq!(n.bits(), 128);
+    assert!(n.bit(127) && !n.bit(128) && !n.bit(1000));
+    assert_eq!(U256::ZERO.bits(), 0);
+    assert_eq!(math::BigUint::<1>::from_u128(u128::MAX).limbs(), [u64::MAX]);
+
+    let n = n.checked_add_u64(1).unwrap();
+    assert_eq!(n, U256::from_limbs([0, 0, 1, 0]));
+    assert_eq!(
+        n.checked_shl(127),
+        Some(U256::from_limbs([0, 0, 0, 1 << 63]))
+    );
+    assert_eq!(n.checked_shl(128), None);
+    assert_eq!(U256::ZERO.checked_shl(1000), Some(U256::ZERO));
+    assert_eq!(
+        U256::from_u128(0x0123_4567_89AB_CDEF).checked_shl(100),
+        Some(U256::from_limbs([0, 0x9ABC_DEF0_0000_0000, 0x1234_5678, 0]))
+    );
+    assert_eq!(
+        U256::from_limbs([0, 0, 0, 1 << 62])
+            .checked_mul_u64(2)
+            .unwrap()
+            .bits(),
+        256
+    );
+    assert_eq!(
+        U256::from_limbs([0, 0, 0, 1 << 62]).checked_mul_u64(4),
+        None
+    );
+    assert_eq!(U256::from_limbs([u64::MAX; 4]).checked_add_u64(1), None);
+
+    assert_eq!(
+        U256::from_str_radix("340282366920938463463374607431768211456", 10),
+        Some(n)
+    );
+    assert_eq!(
+        U256::from_str_radix("100000000000000000000000000000000", 16),
+        Some(n)
+    );
+    assert_eq!(U256::from_str_radix("", 10), None);
+    assert_eq!(U256::from_str_radix("12a", 10), None);
+    assert_eq!(U256::from_str_radix(&"f".repeat(65), 16), None);
+    assert_eq!(
+        U256::from_str_radix(&"f".repeat(64), 16),
+        Some(U256::from_limbs([u64::MAX; 4]))
+    );
+}
+
+#[test]
+fn test_biguint_arithmetic() {
+    type U256 = math::BigUint<4>;
+    // Agrees with u128 for values that fit.
+    let values = [
+        0_u128,
+        1,
+        3,
+        12345,
+        (1 << 64) - 1,
+        1 << 64,
+        0xDC28D76FFD9338E9,
+        0x732E73C316878E244FDFDE4EE623CDCC,
+        u128::MAX,
+    ];
+    for &a in values.iter() {
+        let big_a = U256::from_u128(a);
+        assert_eq!(big_a.isqrt(), U256::from_u128(math::isqrt(a)));
+        for &b in values.iter() {
+            let big_b = U256::from_u128(b);
+            assert_eq!(big_a.cmp(&big_b), a.cmp(&b));
+            if let Some(sum) = a.checked_add(b) {
+                assert_eq!(big_a.checked_add(big_b), Some(U256::from_u128(sum)));
+            }
+            assert_eq!(
+                big_a.checked_sub(big_b),
+                a.checked_sub(b).map(U256::from_u128)
+            );
+            if let Some(product) = a.checked_mul(b) {
+                assert_eq!(big_a.checked_mul(big_b), Some(U256::from_u128(product)));
+            }
+            assert_eq!(
+                big_a.checked_div_rem(big_b),
+                a.checked_div(b)
+                    .map(|q| (U256::from_u128(q), U256::from_u128(a % b)))
+            );
+        }
+    }
+
+    // Beyond u128. (2^128 - 1)² = 2^256 - 2^129 + 1.
+    let max_128 = U256::from_u128(u128::MAX);
+    let square = max_128.checked_mul(max_128).unwrap();
+    assert_eq!(square, U256::from_limbs([1, 0, u64::MAX - 1, u64::MAX]));
+    assert_eq!(square.isqrt(), max_128);
+    assert_eq!(square.checked_div_rem(max_128), Some((max_128, U256::ZERO)));
+    let max = U256::from_limbs([u64::MAX; 4]);
+    assert_eq!(max.isqrt(), max_128);
+    assert_eq!(
+        max.checked_div_rem(square),
+        Some((
+            U256::from_u128(1),
+            U256::from_limbs([u64::MAX - 1, u64::MAX, 1, 0])
+        ))
+    );
+    assert_eq!(
+        max.checked_div_rem(max),
+        Some((U256::from_u128(1), U256::ZERO))
+    );
+    assert_eq!(max.checked_add(U256::from_u128(1)), None);
+    assert_eq!(square.checked_add(U256::from_limbs([0, 0, 2, 0])), None);
+    assert_eq!(
+        max_128.checked_mul(U256::from_limbs([0, 0, 1, 0])),
+        Some(U256::from_limbs([0, 0, u64::MAX, u64::MAX]))
+    );
+    assert_eq!(max_128.checked_mul(U256::from_limbs([0, 0, 2, 0])), None);
+    assert!(U256::from_limbs([0, 0, 0, 1]) > max_128);
+}
+
+#[test]
+fn test_modulo_biguint() {
+    // Agrees with u128 for values that fit.
+    for &a in [
+        0_u128,
+        1,
+        12345,
+        1 << 64,
+        (1 << 64) - 1,
+        u128::MAX,
+        0xDC28D76FFD9338E9D868AF566191DE10,
+    ]
+    .iter()
+    {
+        let big = math::BigUint::<3>::from_u128(a);
+        for &m in [
+            1_u128,
+            2,
+            3,
+            1_000_000_007,
+            (1 << 64) - 1,
+            1 << 64,
+            (1 << 64) + 1,
+            u128::MAX,
+        ]
+        .iter()
+        {
+            assert_eq!(math::modulo(big, m), a % m);
+        }
+        assert_eq!(math::modulo(big, 255_u8), (a % 255) as u8);
+    }
+
+    // 2^256 - 1 = (2^128 - 1)(2^128 + 1), and 2^64 + 1 divides 2^128 - 1.
+    let max = math::BigUint::<4>::from_limbs([u64::MAX; 4]);
+    assert_eq!(math::modulo(max, u128::MAX), 0);
+    assert_eq!(math::modulo(max, (1_u128 << 64) + 1), 0);
+    assert_eq!(math::modulo(max, 1_u64 << 32), 0xFFFFFFFF);
+    assert_eq!(math::modulo(max, 1_000_000_007_u64), 792845265);
+    assert_eq!(math::checked_modulo(max, 0_u32), None);
+}
@@ -0,0 +1,69 @@
+use ssrand::math;
+use ssrand::mwcsearch::MwcSearch;
+
+#[test]
+fn test_search_8_bit() {
+    // Compare with a brute-force search, using trial division.
+    let is_prime = |n: u64| {
+        n >= 2
+            && (2..n)
+                .take_while(|d| d * d <= n)
+                .all(|d| n % d != 0)
+    };
+    let expected: Vec<u64> = (2..256)
+        .rev()
+        .filter(|&a| {
+            let p = (a << 8) - 1;
+            is_prime(p) && is_prime((p - 1) / 2)
+        })
+        .collect();
+    let found: Vec<u64> = MwcSearch::new(8, 1).map(|c| c.multiplier).collect();
+    assert_eq!(found, expected);
+    assert_eq!(found[..3], [210, 204, 174]);
+
+    for candidate in MwcSearch::new(8, 1) {
+        assert_eq!(candidate.modulus, (candidate.multiplier << 8) - 1);
+        // The period is the order of the base, 2^8.
+        assert_eq!(math::pow_mod(256, candidate.period, candidate.modulus), 1);
+        assert!(candidate.score > 0.0 && candidate.score <= 1.0);
+    }
+}
+
+#[test]
+fn test_crate_multipliers() {
+    // The multipliers of MWC2 and MWC64 are found.
+    let search = MwcSearch::new(16, 1);
+    for &multiplier in [36969, 18000].iter() {
+        let candidate = search.candidate(multiplier).unwrap();
+        assert_eq!(candidate.period, (multiplier << 16) / 2 - 1);
+    }
+    assert!(search.clone().any(|c| c.multiplier == 36969));
+    assert_eq!(search.candidate(36970), None);
+    assert_eq!(search.candidate(0x10000), None);
+
+    let candidate = MwcSearch::new(32, 1).candidate(698769069).unwrap();
+    assert_eq!(candidate.period, (698769069 << 32) / 2 - 1);
+}
+
+#[test]
+fn test_spectral_score() {
+    // For lag 1 the equivalent multiplier is a, and the shortest dual vector is (a, -1).
+    let candidate = MwcSearch::new(16, 1).candidate(36969).unwrap();
+    assert_eq!(candidate.spectral.nu_squared(2), 36969 * 36969 + 1);
+    assert!((candidate.spectral.score(2) - 0.698947).abs() < 1e-6);
+    // The worst dimension is 3, with ν₃² = 2006.
+    assert_eq!(candidate.spectral.nu_squared(3), 2006);
+    assert!((candidate.score - 0.029709).abs() < 1e-6);
+    assert_eq!(candidate.score, candidate.spectral.score(3));
+
+    // For lag 2, the shortest dual vector is (b, -1).
+    let candidate = MwcSearch::new(16, 2).next().unwrap();
+    assert_eq!(candidate.spectral.nu_squared(2), (1 << 32) + 1);
+}
+
+#[test]
+#[should_panic(expected = "modulus is too large")]
+fn test_search_too_large() {
+    // word_bits·lag overflows u32.
+    MwcSearch::new(65536, 65536);
+}
@@ -0,0 +1,232 @@
+use std::collections::HashSet;
+
+use rand_core::{RngCore, SeedableRng};
+use ssrand::anyrng::AnyRng;
+use ssrand::seed::{mix32, FromSeedSequence, SeedError, SeedSequence};
+use ssrand::{Cong, KISS, KISS2, LFSR113, LFSR88, MWC1, MWC2, MWC64, SHR3};
+
+const MWC2_UPPER_MOD: u32 = (36969 << 16) - 1;
+const MWC2_LOWER_MOD: u32 = (18000 << 16) - 1;
+const MWC64_M: u32 = 698769069;
+
+fn error(
+    generator: &'static str,
+    component: &'static str,
+    seed: usize,
+    value: u64,
+    replacement: Option<u64>,
+) -> SeedError {
+    SeedError {
+        generator,
+        component,
+        seed,
+        value,
+        replacement,
+    }
+}
+
+#[test]
+fn test_try_new_valid() {
+    // Good seeds give the same generator as new().
+    assert_eq!(Cong::try_new(0), Ok(Cong::new(0)));
+    assert_eq!(SHR3::try_new(1), Ok(SHR3::new(1)));
+    assert_eq!(MWC1::try_new(1, 2), Ok(MWC1::new(1, 2)));
+    assert_eq!(MWC2::try_new(1, 2), Ok(MWC2::new(1, 2)));
+    assert_eq!(MWC64::try_new(0, 1), Ok(MWC64::new(0, 1)));
+    let mut kiss = KISS::try_new(2247183469, 99545079, 3269400377, 3950144837).unwrap();
+    let mut kiss_ref = KISS::new(2247183469, 99545079, 3269400377, 3950144837);
+    assert_eq!(kiss.next_u32(), kiss_ref.next_u32());
+    assert!(KISS2::try_new(1, 2, 0, 4).is_ok());
+    assert!(LFSR88::try_new(2, 8, 16).is_ok());
+    assert!(LFSR113::try_new(2, 8, 16, 128).is_ok());
+
+    // A state above the modulus is only reduced, so it's accepted.
+    assert!(MWC2::try_new(MWC2_UPPER_MOD + 1, MWC2_LOWER_MOD + 1).is_ok());
+}
+
+#[test]
+fn test_try_new_shr3() {
+    let expected = error("SHR3", "SHR3", 1, 0, Some(0xFFFFFFFF));
+    assert_eq!(SHR3::try_new(0), Err(expected));
+    assert_eq!(
+        expected.to_string(),
+        "SHR3 seed1 gives degenerate SHR3 state 0x0, which would be replaced by 0xFFFFFFFF"
+    );
+
+    // The replacement is what new() uses.
+    let mut rng = SHR3::new(0);
+    let mut rng_ref = SHR3::new(0xFFFFFFFF);
+    assert_eq!(rng.next_u32(), rng_ref.next_u32());
+}
+
+#[test]
+fn test_try_new_mwc() {
+    assert_eq!(
+        MWC2::try_new(0, 1),
+        Err(error("MWC2", "MWC2 upper", 1, 0, Some(0x6F970000)))
+    );
+    assert_eq!(
+        MWC1::try_new(1, MWC2_LOWER_MOD),
+        Err(error(
+            "MWC1",
+            "MWC2 lower",
+            2,
+            MWC2_LOWER_MOD as u64,
+            Some(0x73600001),
+        ))
+    );
+    // Larger multiples of the modulus stay stuck.
+    let expected = error("MWC2", "MWC2 lower", 2, 2 * MWC2_LOWER_MOD as u64, None);
+    assert_eq!(MWC2::try_new(1, 2 * MWC2_LOWER_MOD), Err(expected));
+    assert_eq!(
+        expected.to_string(),
+        "MWC2 seed2 gives degenerate MWC2 lower state 0x8C9FFFFE, which can't be replaced"
+    );
+    assert_eq!(
+        MWC2::try_new(1, 3 * MWC2_LOWER_MOD)
+            .unwrap_err()
+            .replacement,
+        None
+    );
+
+    // MWC64's state comes from both seeds.
+    let modulus = ((MWC64_M as u64) << 32) - 1;
+    assert_eq!(
+        MWC64::try_new(0, 0),
+        Err(error("MWC64", "MWC64", 1, 0, Some(u64::MAX - modulus)))
+    );
+    assert_eq!(
+        MWC64::try_new(MWC64_M - 1, 0xFFFFFFFF),
+        Err(error(
+            "MWC64",
+            "MWC64",
+            1,
+            modulus,
+            Some(u64::MAX - 2 * modulus)
+        ))
+    );
+    assert_eq!(
+        MWC64::try_new(2 * MWC64_M - 1, 0xFFFFFFFE),
+        Err(error("MWC64", "MWC64", 1, 2 * modulus, None))
+    );
+}
+
+#[test]
+fn test_try_new_kiss() {
+    // The first degenerate component is reported, named for the containing generator.
+    assert_eq!(
+        KISS::try_new(1, 2, 3, 0),
+        Err(error("KISS", "SHR3", 4, 0, Some(0xFFFFFFFF)))
+    );
+    assert_eq!(
+        KISS::try_new(1, 0, 3, 0).unwrap_err().component,
+        "MWC2 lower"
+    );
+    assert_eq!(KISS2::try_new(0, 0, 3, 4).unwrap_err().component, "MWC64");
+    assert_eq!(KISS2::try_new(1, 2, 3, 0).unwrap_err().seed, 4);
+}
+
+#[test]
+fn test_try_new_lfsr() {
+    // The state is seed ^ (seed << 16).
+    assert!(LFSR88::try_new(2, 7, 16).is_ok());
+    assert_eq!(
+        LFSR88::try_new(2, 0x00070007, 16),
+        Err(error("LFSR88", "z2", 2, 7, Some(0xFFFFFFF8)))
+    );
+    assert_eq!(
+        LFSR113::try_new(2, 8, 16, 0x007F007F),
+        Err(error("LFSR113", "z4", 4, 0x7F, Some(0xFFFFFF80)))
+    );
+    assert_eq!(LFSR113::try_new(0, 0, 0, 0).unwrap_err().component, "z1");
+}
+
+#[test]
+fn test_mix32() {
+    assert_eq!(mix32(0), 0);
+    assert_eq!(mix32(1), 0x688990C0);
+    assert_eq!(mix32(0x12345678), 0xF5E71C96);
+    // A bijection, so no collisions.
+    let mut outputs: Vec<u32> = (0..0x10000).map(|x| mix32(x << 8)).collect();
+    outputs.sort_unstable();
+    outputs.dedup();
+    assert_eq!(outputs.len(), 0x10000);
+}
+
+#[test]
+fn test_seed_sequence() {
+    // Fixed values, for ports to match.
+    let mut seq = SeedSequence::from_u64(42);
+    let seeds: Vec<u32> = (0..4).map(|_| seq.next_seed()).collect();
+    assert_eq!(seeds, [0x9EC7A148, 0xC97B7891, 0x75AB4B47, 0x09205B33]);
+    let mut seq = SeedSequence::from_bytes(b"ssrand");
+    let seeds: Vec<u32> = (0..4).map(|_| seq.next_seed()).collect();
+    assert_eq!(seeds, [0x3B77099D, 0x00F59C08, 0xB5A3821C, 0x7A62D0E1]);
+    let mut seq = SeedSequence::from_bytes(&[0; 16]);
+    let seeds: Vec<u32> = (0..4).map(|_| seq.next_seed()).collect();
+    assert_eq!(seeds, [0x5538BA67, 0xE79C848F, 0x5AE6F009, 0x9AC9B781]);
+    let mut seq = SeedSequence::from_bytes(&[]);
+    assert_eq!(seq.next_seed(), 0xAE6F80F1);
+
+    assert_eq!(
+        SeedSequence::from_u64(42),
+        SeedSequence::from_bytes(&42_u64.to_le_bytes())
+    );
+    // The length is part of the hash, so zero padding doesn't collide.
+    assert_ne!(
+        SeedSequence::from_bytes(&[1]),
+        SeedSequence::from_bytes(&[1, 0])
+    );
+}
+
+#[test]
+fn test_seed_sequence_generate() {
+    let kiss = KISS::seed_from_u64(42);
+    assert_eq!(
+        kiss,
+        KISS::new(0x9EC7A148, 0xC97B7891, 0x75AB4B47, 0x09205B33)
+    );
+    assert_eq!(
+        kiss,
+        KISS::from_seed_sequence(&mut SeedSequence::from_u64(42))
+    );
+    assert_eq!(
+        LFSR88::from_seed([0; 12]),
+        LFSR88::from_seed_sequence(&mut SeedSequence::from_bytes(&[0; 12]))
+    );
+    let mut rng = AnyRng::from_seed_sequence("kiss", &mut SeedSequence::from_u64(42)).unwrap();
+    assert_eq!(rng.next_u32(), kiss.clone().next_u32());
+    assert!(AnyRng::from_seed_sequence("nosuch", &mut SeedSequence::from_u64(42)).is_err());
+
+    // A rejected set of seeds is discarded, and the next set is tried.
+    let mut seq = SeedSequence::from_u64(42);
+    let seeds = seq.generate(|seeds: [u32; 2]| {
+        if seeds[0] == 0x9EC7A148 {
+            SHR3::try_new(0).map(|_| seeds)
+        } else {
+            Ok(seeds)
+        }
+    });
+    assert_eq!(seeds, [0x75AB4B47, 0x09205B33]);
+}
+
+#[test]
+fn test_from_seed_distinct() {
+    // Every byte of the seed matters, including the upper 8 bytes of a 16-byte seed.
+    let mut seed = [0; 16];
+    seed[8] = 1;
+    assert_eq!(
+        KISS::from_seed(seed),
+        KISS::new(0xDC1D8957, 0x0E2F0C42, 0x0A4F3D60, 0xAB63C20E)
+    );
+    assert_ne!(KISS::from_seed(seed), KISS::from_seed([0; 16]));
+    seed[15] = 0x80;
+    assert_ne!(KISS2::from_seed(seed), KISS2::from_seed([0; 16]));
+    assert_ne!(LFSR113::from_seed(seed), LFSR113::from_seed([0; 16]));
+
+    // For a seed of one word, the seed word is a bijection of it.
+    let states: HashSet<Cong> = (0..0x10000_u32)
+        .map(|x| Cong::from_seed((x * 0x10001).to_le_bytes()))
+        .collect();
+    assert_eq!(states.len(), 0x10000);
+}
@@ -0,0 +1,129 @@
+use ssrand::spectral::spectral_test;
+
+/// ν_t² by exhaustive search: the shortest non-zero (s1, ..., st) with
+/// s1 + a·s2 + ... + a^(t-1)·st = 0 mod m.
+fn brute_force_nu_squared(a: u64, m: u64, t: usize) -> u128 {
+    let m = m as i64;
+    let mut powers = vec![1_i64; t];
+    for i in 1..t {
+        powers[i] = powers[i - 1] * a as i64 % m;
+    }
+    // Hermite's bound ν_t² <= γ_t·m^(2/t), with γ_t <= 2 for t <= 8.
+    let limit = (2.0 * (m as f64).powf(2.0 / t as f64)).sqrt() as i64 + 1;
+    let mut best = u128::MAX;
+    let mut s = vec![-limit; t - 1];
+    loop {
+        // s1 is determined modulo m by s2..st; take the representative nearest 0.
+        let sum: i64 = s
+            .iter()
+            .zip(powers[1..].iter())
+            .map(|(&x, &p)| x * p)
+            .sum::<i64>()
+            .rem_euclid(m);
+        let s1 = if sum > m / 2 { m - sum } else { -sum };
+        let norm = (s1 * s1) as u128 + s.iter().map(|&x| (x * x) as u128).sum::<u128>();
+        if norm != 0 {
+            best = best.min(norm);
+        }
+        // Next s.
+        let mut i = 0;
+        loop {
+            if i == t - 1 {
+                return best;
+            }
+            if s[i] < limit {
+                s[i] += 1;
+                break;
+            }
+            s[i] = -limit;
+            i += 1;
+        }
+    }
+}
+
+#[test]
+fn test_brute_force() {
+    for &m in [101_u64, 1009, 1024, 4093, 65536].iter() {
+        for a in (3..m).step_by((m / 7) as usize) {
+            let max_dimension = if m > 5000 { 4 } else { 6 };
+            let result = spectral_test(a as u128, m as u128, max_dimension).unwrap();
+            for t in 2..=max_dimension {
+                assert_eq!(
+                    result.nu_squared(t),
+                    brute_force_nu_squared(a, m, t),
+                    "a {} m {} t {}",
+                    a,
+                    m,
+                    t
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_randu() {
+    let randu = spectral_test(65539, 1 << 31, 8).unwrap();
+    assert_eq!(randu.nu_squared(3), 118);
+    assert!(randu.score(3) < 0.01);
+    assert!(randu.min_score() < 0.01);
+}
+
+#[test]
+fn test_cong() {
+    // Knuth's table of the spectral test, line 22: a = 69069, m = 2^32.
+    let cong = spectral_test(69069, 1 << 32, 8).unwrap();
+    assert_eq!(cong.multiplier(), 69069);
+    assert_eq!(cong.modulus(), 1 << 32);
+    let nu_squared: Vec<u128> = (2..=6).map(|t| cong.nu_squared(t)).collect();
+    assert_eq!(nu_squared, [4243209856, 2072544, 52804, 6990, 242]);
+    let mu = [3.10, 2.91, 3.20, 5.01, 0.017];
+    for (t, &mu_t) in (2..=6).zip(mu.iter()) {
+        assert!((cong.mu(t) - mu_t).abs() < 0.01 * mu_t, "{}", t);
+    }
+    for t in 2..=8 {
+        assert!(cong.score(t) > 0.0 && cong.score(t) <= 1.0);
+        if t > 2 {
+            // Increasing the dimension can only shorten the shortest vector.
+            assert!(cong.nu_squared(t) <= cong.nu_squared(t - 1));
+        }
+    }
+    assert!((cong.min_score() - cong.score(6)).abs() < 1e-12);
+}
+
+#[test]
+fn test_64_bit_modulus() {
+    // Knuth's MMIX multiplier, as used by PCG. ν₂² is from a separate Gauss reduction.
+    let mmix = spectral_test(6364136223846793005, 1 << 64, 8).unwrap();
+    assert_eq!(mmix.modulus(), 1 << 64);
+    assert_eq!(mmix.nu_squared(2), 8810664174654508192);
+    for t in 3..=8 {
+        assert!(mmix.nu_squared(t) <= mmix.nu_squared(t - 1));
+    }
+    assert!(mmix.min_score() > 0.6 && mmix.min_score() <= 1.0);
+
+    // For a = 2^32 + 1, (a - 1)² = 0 mod 2^64, so a² = 2a - 1 and a³ = 3a - 2. Then (1, -2, 1)
+    // and (-1, 1, 1, -1) are in the dual lattice, and nothing shorter is.
+    let bad = spectral_test((1 << 32) + 1, 1 << 64, 4).unwrap();
+    assert_eq!(bad.nu_squared(3), 6);
+    assert_eq!(bad.nu_squared(4), 4);
+}
+
+#[test]
+fn test_large_modulus() {
+    // Beyond 2^64, as far as 256-bit arithmetic allows. ν₂² is from a Gauss reduction.
+    let result = spectral_test(0x2360ED051FC65DA44385DF649FCCF645, 1 << 127, 3).unwrap();
+    assert_eq!(result.nu_squared(2), 67328196238967660415947728022709418298);
+
+    let error = spectral_test(u128::MAX - 1, u128::MAX, 3).unwrap_err();
+    assert_eq!(error.multiplier, u128::MAX - 1);
+    assert_eq!(error.dimension, 2);
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "spectral test of multiplier {} modulo {} overflowed in dimension 2",
+            u128::MAX - 1,
+            u128::MAX
+        )
+    );
+}
@@ -0,0 +1,121 @@
+use ssrand::stats;
+
+#[test]
+fn test_chi_square_p_value() {
+    // Reference values from standard chi-square tables.
+    assert!((stats::chi_square_p_value(3.841, 1) - 0.05).abs() < 1e-4);
+    assert!((stats::chi_square_p_value(6.635, 1) - 0.01).abs() < 1e-4);
+    assert!((stats::chi_square_p_value(11.070, 5) - 0.05).abs() < 1e-4);
+    assert!((stats::chi_square_p_value(293.248, 255) - 0.05).abs() < 1e-4);
+    assert!((stats::chi_square_p_value(255.0, 255) - 0.4882).abs() < 1e-3);
+    assert_eq!(stats::chi_square_p_value(0.0, 3), 1.0);
+}
+
+#[test]
+fn test_normal_poisson_p_value() {
+    assert!((stats::normal_p_value(1.959964) - 0.05).abs() < 1e-6);
+    assert!((stats::normal_p_value(-2.575829) - 0.01).abs() < 1e-6);
+    // For Poisson(16), P(X <= 8) = 0.0219873 and P(X >= 25) = 0.0223155.
+    assert!((stats::poisson_p_value(8, 16.0) - 2.0 * 0.0219873).abs() < 1e-6);
+    assert!((stats::poisson_p_value(25, 16.0) - 2.0 * 0.0223155).abs() < 1e-6);
+    assert_eq!(stats::poisson_p_value(16, 16.0), 1.0);
+}
+
+#[test]
+fn test_rank_probability() {
+    // Reference values from Marsaglia's Diehard binary rank tests.
+    assert!((stats::rank_probability(32, 32) - 0.2887880952).abs() < 1e-9);
+    assert!((stats::rank_probability(32, 31) - 0.5775761902).abs() < 1e-9);
+    assert!((stats::rank_probability(32, 30) - 0.1283502644).abs() < 1e-9);
+    assert!((stats::rank_probability(31, 31) - 0.2887880952).abs() < 1e-9);
+    assert!((stats::rank_probability(6, 6) - 0.2933478).abs() < 1e-6);
+    let total: f64 = (0..=8).map(|r| stats::rank_probability(8, r)).sum();
+    assert!((total - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_rank_distribution() {
+    let mut rng = ssrand::KISS::new(2247183469, 99545079, 3269400377, 3950144837);
+    for &size in [31, 32].iter() {
+        let distribution = stats::rank_distribution(&mut rng, size, 5_000);
+        assert_eq!(distribution.size, size);
+        assert_eq!(distribution.counts.iter().sum::<u64>(), 5_000);
+        assert!((distribution.probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        let result = &distribution.result;
+        assert!(
+            result.p_value > 1e-4 && result.p_value < 1.0 - 1e-4,
+            "{}",
+            result
+        );
+    }
+
+    // 32 successive SHR3 outputs are always linearly independent. The top 31 bits of 31 outputs
+    // are not so constrained.
+    let mut rng = ssrand::SHR3::new(3360276411);
+    let distribution = stats::rank_distribution(&mut rng, 32, 1_000);
+    assert_eq!(distribution.counts, [0, 0, 0, 1_000]);
+    let distribution = stats::rank_distribution(&mut rng, 31, 1_000);
+    assert!(distribution.counts[3] < 1_000);
+}
+
+#[test]
+fn test_kiss_passes() {
+    let mut rng = ssrand::KISS::new(2247183469, 99545079, 3269400377, 3950144837);
+    let results = [
+        stats::frequency(&mut rng, 100_000),
+        stats::serial(&mut rng, 100_000),
+        stats::gap(&mut rng, 50_000),
+        stats::poker(&mut rng, 20_000),
+        stats::birthday_spacings(&mut rng, 50),
+        stats::binary_rank(&mut rng, 5_000),
+        stats::runs(&mut rng, 100_000),
+        stats::collision(&mut rng, 20),
+    ];
+    for result in results.iter() {
+        assert!(
+            result.p_value > 1e-4 && result.p_value < 1.0 - 1e-4,
+            "{}",
+            result
+        );
+    }
+}
+
+#[test]
+fn test_shr3_fails_binary_rank() {
+    // 32 successive SHR3 outputs are always linearly independent, so every matrix has rank 32.
+    let mut rng = ssrand::SHR3::new(3360276411);
+    let result = stats::binary_rank(&mut rng, 1_000);
+    assert!(result.p_value < 1e-10, "{}", result);
+}
+
+#[test]
+fn test_cong_fails_low_bits() {
+    // The low bits of Cong are too regular. Rotating them into the high bits, where the tests look,
+    // shows up the weakness.
+    struct LowBits(ssrand::Cong);
+    impl rand_core::RngCore for LowBits {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32().rotate_right(8)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_u32(self)
+        }
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dest)
+        }
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+    let mut rng = LowBits(ssrand::Cong::new(2051391225));
+    let result = stats::serial(&mut rng, 100_000);
+    assert!(result.p_value < 1e-10, "{}", result);
+}
+
+#[test]
+#[should_panic(expected = "runs test needs n of at least 4000")]
+fn test_runs_too_few() {
+    let mut rng = ssrand::KISS::new(2247183469, 99545079, 3269400377, 3950144837);
+    stats::runs(&mut rng, 6);
+}
@@ -0,0 +1,157 @@
+use ::ssrand::bitmatrix::BitMatrix;
+use ::ssrand::widebitmatrix::WideBitMatrix;
+
+use num_traits::{One, Pow, Zero};
+
+/// One step of Marsaglia's xorshift128, with state x, y, z, w in 32-bit parts of the vector.
+fn xorshift128_step(state: &[u64; 2]) -> [u64; 2] {
+    let x = state[0] as u32;
+    let y = (state[0] >> 32) as u32;
+    let z = state[1] as u32;
+    let w = (state[1] >> 32) as u32;
+    let t = x ^ (x << 11);
+    let w_new = w ^ (w >> 19) ^ (t ^ (t >> 8));
+    [
+        y as u64 | ((z as u64) << 32),
+        w as u64 | ((w_new as u64) << 32),
+    ]
+}
+
+/// One step of Marsaglia's 160-bit xorshift, as used in xorwow, with state x, y, z, w, v.
+fn xorshift160_step(state: &[u32; 5]) -> [u32; 5] {
+    let t = state[0] ^ (state[0] >> 2);
+    let v = state[4];
+    [
+        state[1],
+        state[2],
+        state[3],
+        v,
+        (v ^ (v << 4)) ^ (t ^ (t << 1)),
+    ]
+}
+
+#[test]
+fn test_zero_one() {
+    let zero = WideBitMatrix::<u8, 4, 31>::zero();
+    let one = WideBitMatrix::<u8, 4, 31>::one();
+    assert!(zero.is_zero());
+    assert!(!one.is_zero());
+    let v = [0x12, 0x34, 0x56, 0x78];
+    assert_eq!(one.dot_vec(&v), v);
+    assert_eq!(zero.dot_vec(&v), [0; 4]);
+    assert_eq!(one.inverse(), Some(one.clone()));
+    assert_eq!(zero.inverse(), None);
+}
+
+#[test]
+#[should_panic(expected = "WORDS is too few for WIDTH")]
+fn test_one_too_few_words() {
+    WideBitMatrix::<u8, 1, 16>::one();
+}
+
+#[test]
+#[should_panic(expected = "WORDS is too few for WIDTH")]
+fn test_new_too_few_words() {
+    WideBitMatrix::<u8, 1, 16>::new(&[[0]; 16]);
+}
+
+#[test]
+fn test_pow_matches_bitmatrix() {
+    const SHR3_MATRIX_ARRAY: [u32; 32] = [
+        0x00042021, 0x00084042, 0x00108084, 0x00210108, 0x00420231, 0x00840462, 0x010808C4,
+        0x02101188, 0x04202310, 0x08404620, 0x10808C40, 0x21011880, 0x42023100, 0x84046200,
+        0x0808C400, 0x10118800, 0x20231000, 0x40462021, 0x808C4042, 0x01080084, 0x02100108,
+        0x04200210, 0x08400420, 0x10800840, 0x21001080, 0x42002100, 0x84004200, 0x08008400,
+        0x10010800, 0x20021000, 0x40042000, 0x80084000,
+    ];
+    let shr3_matrix = BitMatrix::<u32, 32>::new(&SHR3_MATRIX_ARRAY);
+    let mut columns = [[0_u8; 4]; 32];
+    for (column, &value) in columns.iter_mut().zip(SHR3_MATRIX_ARRAY.iter()) {
+        *column = value.to_le_bytes();
+    }
+    let shr3_wide_matrix = WideBitMatrix::<u8, 4, 32>::new(&columns);
+
+    let result = shr3_matrix.pow(1_000_000_000_u32);
+    let result_wide = shr3_wide_matrix.pow(1_000_000_000_u32);
+    for i in 0..32 {
+        let unit = 1_u32 << i;
+        assert_eq!(
+            result_wide.dot_vec(&unit.to_le_bytes()),
+            result.dot_vec(unit).to_le_bytes()
+        );
+    }
+}
+
+#[test]
+fn test_xorshift128() {
+    let mut columns = [[0_u64; 2]; 128];
+    let mut columns_u128 = [0_u128; 128];
+    for i in 0..128 {
+        let mut unit = [0_u64; 2];
+        unit[i / 64] = 1 << (i % 64);
+        columns[i] = xorshift128_step(&unit);
+        columns_u128[i] = columns[i][0] as u128 | ((columns[i][1] as u128) << 64);
+    }
+    let matrix = WideBitMatrix::<u64, 2, 128>::new(&columns);
+    let matrix_u128 = BitMatrix::<u128, 128>::new(&columns_u128);
+
+    let seed = [0x075BCD15_3ADE68B1, 0x159A55E5_1F123BB5];
+    let mut state = seed;
+    for _ in 0..1000 {
+        state = xorshift128_step(&state);
+    }
+    let jumped = matrix.clone().pow(1000_u32).dot_vec(&seed);
+    assert_eq!(jumped, state);
+    let jumped_u128 = matrix_u128
+        .pow(1000_u32)
+        .dot_vec(seed[0] as u128 | ((seed[1] as u128) << 64));
+    assert_eq!(jumped_u128, state[0] as u128 | ((state[1] as u128) << 64));
+
+    // Jump back.
+    assert_eq!(matrix.pow(-1000_i32).dot_vec(&state), seed);
+}
+
+#[test]
+fn test_xorshift160() {
+    let mut columns = [[0_u32; 5]; 160];
+    for (i, column) in columns.iter_mut().enumerate() {
+        let mut unit = [0_u32; 5];
+        unit[i / 32] = 1 << (i % 32);
+        *column = xorshift160_step(&unit);
+    }
+    let matrix = WideBitMatrix::<u32, 5, 160>::new(&columns);
+
+    let seed = [123456789, 362436069, 521288629, 88675123, 5783321];
+    let mut state = seed;
+    for _ in 0..12345 {
+        state = xorshift160_step(&state);
+    }
+    assert_eq!(matrix.clone().pow(12345_u64).dot_vec(&seed), state);
+
+    let inverse = matrix.inverse().unwrap();
+    assert_eq!(&inverse * &matrix, WideBitMatrix::one());
+    assert_eq!(
+        matrix.clone().pow(-7_i8) * matrix.clone().pow(7_u8),
+        WideBitMatrix::one()
+    );
+    assert_eq!(matrix.clone() + matrix, WideBitMatrix::zero());
+}
+
+#[test]
+fn test_mersenne_twister_size() {
+    // 19937 bits in 624 words: about 48 MiB, which would overflow the stack.
+    let one = WideBitMatrix::<u32, 624, 19937>::one();
+    let mut v = [0_u32; 624];
+    for (i, word) in v.iter_mut().enumerate() {
+        *word = (i as u32).wrapping_mul(0x9E3779B9);
+    }
+    v[623] &= 1;
+    assert_eq!(one.dot_vec(&v), v);
+
+    let from_fn = WideBitMatrix::<u32, 624, 19937>::from_fn(|i| {
+        let mut unit = [0_u32; 624];
+        unit[i / 32] = 1 << (i % 32);
+        unit
+    });
+    assert_eq!(from_fn, one);
+}